@@ -0,0 +1,235 @@
+//! Auctions for commodities.
+//!
+//! The owner of a commodity may sell it in an English (ascending price) auction with a reserve
+//! price and an end block. Bids must meet the reserve price and exceed the current highest bid,
+//! and the funds of the highest bidder are reserved until it is outbid or the auction ends.
+//! English auctions are settled at the end of their end block: the commodity is sold to the
+//! highest bidder, provided that it is still owned by the seller, and otherwise the highest bid
+//! is refunded.
+//!
+//! The owner of a commodity may also sell it in a Dutch (descending price) auction. The price of
+//! the commodity decays linearly with each block from a start price to a floor price, which is
+//! reached at the end block, and the first account to buy the commodity pays the price at the
+//! block in which it buys it. A Dutch auction that has not been won by the end of its end block
+//! is closed.
+
+use super::*;
+use frame_support::traits::ReservableCurrency;
+use sp_runtime::traits::AtLeast32BitUnsigned;
+
+/// An auction for a commodity.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Auction<AccountId, Balance, BlockNumber> {
+    /// The account that started the auction and that will be paid when it is settled.
+    pub seller: AccountId,
+    /// The lowest bid that will be accepted.
+    pub reserve_price: Balance,
+    /// The block at the end of which the auction will be settled.
+    pub end: BlockNumber,
+    /// The highest bidder and its bid, if any bids have been placed.
+    pub highest_bid: Option<(AccountId, Balance)>,
+}
+
+/// An auction for a commodity of this pallet.
+pub type AuctionOf<T, I> = Auction<
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T, I>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+/// A Dutch (descending price) auction for a commodity.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct DutchAuction<AccountId, Balance, BlockNumber> {
+    /// The account that started the auction and that will be paid when the commodity is bought.
+    pub seller: AccountId,
+    /// The price at the start block.
+    pub start_price: Balance,
+    /// The price at the end block.
+    pub floor_price: Balance,
+    /// The block at which the auction started.
+    pub start: BlockNumber,
+    /// The block at the end of which the auction will be closed.
+    pub end: BlockNumber,
+}
+
+impl<AccountId, Balance, BlockNumber> DutchAuction<AccountId, Balance, BlockNumber>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+    /// The price of the commodity at the specified block, which decays linearly from the start
+    /// price at the start block to the floor price at the end block.
+    pub fn price_at(&self, now: BlockNumber) -> Balance {
+        if now >= self.end {
+            return self.floor_price;
+        }
+
+        let elapsed = now.saturating_sub(self.start);
+        let duration = self.end.saturating_sub(self.start);
+        let decay = Perbill::from_rational_approximation(elapsed, duration)
+            * self.start_price.saturating_sub(self.floor_price);
+        self.start_price.saturating_sub(decay)
+    }
+}
+
+/// A Dutch auction for a commodity of this pallet.
+pub type DutchAuctionOf<T, I> = DutchAuction<
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T, I>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// Start an English auction for a commodity that is not already being auctioned.
+    pub(crate) fn open_english_auction(
+        seller: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+        reserve_price: BalanceOf<T, I>,
+        end: T::BlockNumber,
+    ) -> DispatchResult {
+        Self::schedule_auction_end(commodity_id, end)?;
+        Auctions::<T, I>::insert(
+            commodity_id,
+            Auction {
+                seller: seller.clone(),
+                reserve_price,
+                end,
+                highest_bid: None,
+            },
+        );
+        Listings::<T, I>::remove(commodity_id);
+
+        Ok(())
+    }
+
+    /// Start a Dutch auction for a commodity that is not already being auctioned.
+    pub(crate) fn open_dutch_auction(
+        seller: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+        start_price: BalanceOf<T, I>,
+        floor_price: BalanceOf<T, I>,
+        end: T::BlockNumber,
+    ) -> DispatchResult {
+        ensure!(
+            floor_price <= start_price,
+            Error::<T, I>::InvalidAuctionPrices
+        );
+        Self::schedule_auction_end(commodity_id, end)?;
+        DutchAuctions::<T, I>::insert(
+            commodity_id,
+            DutchAuction {
+                seller: seller.clone(),
+                start_price,
+                floor_price,
+                start: frame_system::Module::<T>::block_number(),
+                end,
+            },
+        );
+        Listings::<T, I>::remove(commodity_id);
+
+        Ok(())
+    }
+
+    /// The current price of a commodity that is being sold in a Dutch auction, if the commodity is
+    /// still owned by the seller.
+    pub fn dutch_auction_price(commodity_id: &CommodityId<T>) -> Option<BalanceOf<T, I>> {
+        Self::dutch_auction(commodity_id)
+            .filter(|auction| Self::account_for_commodity(commodity_id) == auction.seller)
+            .map(|auction| auction.price_at(frame_system::Module::<T>::block_number()))
+    }
+
+    /// Close the Dutch auction for a commodity, if there is one, and sell the commodity. Either
+    /// both happen or neither does.
+    #[transactional]
+    pub(crate) fn sell_at_auction_price(
+        seller: &T::AccountId,
+        buyer: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+        price: BalanceOf<T, I>,
+    ) -> DispatchResult {
+        // A commodity may not be transferred while it is being auctioned.
+        Self::close_dutch_auction(commodity_id);
+        Self::sell(seller, buyer, commodity_id, price)
+    }
+
+    /// Close the Dutch auction for a commodity that is being bought.
+    fn close_dutch_auction(commodity_id: &CommodityId<T>) {
+        if let Some(auction) = DutchAuctions::<T, I>::take(commodity_id) {
+            AuctionsEndingAt::<T, I>::mutate(auction.end, |commodity_ids| {
+                commodity_ids.retain(|id| id != commodity_id)
+            });
+        }
+    }
+
+    /// Record that the auction for a commodity ends at the end block.
+    fn schedule_auction_end(commodity_id: &CommodityId<T>, end: T::BlockNumber) -> DispatchResult {
+        ensure!(
+            end > frame_system::Module::<T>::block_number(),
+            Error::<T, I>::InvalidAuctionEnd
+        );
+        ensure!(
+            AuctionsEndingAt::<T, I>::decode_len(end).unwrap_or(0)
+                < T::MaxAuctionsEndingPerBlock::get() as usize,
+            Error::<T, I>::TooManyAuctionsEnding
+        );
+
+        AuctionsEndingAt::<T, I>::append(end, commodity_id);
+        Ok(())
+    }
+
+    /// Place a bid on an auction, reserving the bidder's funds and releasing those of the
+    /// previous highest bidder.
+    pub(crate) fn place_bid(
+        bidder: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+        amount: BalanceOf<T, I>,
+    ) -> DispatchResult {
+        Auctions::<T, I>::try_mutate(commodity_id, |maybe_auction| {
+            let auction = maybe_auction.as_mut().ok_or(Error::<T, I>::NotAuctioned)?;
+            ensure!(
+                frame_system::Module::<T>::block_number() <= auction.end,
+                Error::<T, I>::AuctionExpired
+            );
+            match &auction.highest_bid {
+                Some((_, highest_bid)) => ensure!(amount > *highest_bid, Error::<T, I>::BidTooLow),
+                None => ensure!(amount >= auction.reserve_price, Error::<T, I>::BidTooLow),
+            }
+
+            T::Currency::reserve(bidder, amount)?;
+            if let Some((previous_bidder, previous_bid)) =
+                auction.highest_bid.replace((bidder.clone(), amount))
+            {
+                T::Currency::unreserve(&previous_bidder, previous_bid);
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Settle all of the English auctions and close all of the Dutch auctions that end at the
+    /// specified block.
+    pub(crate) fn settle_auctions(now: T::BlockNumber) {
+        for commodity_id in AuctionsEndingAt::<T, I>::take(now) {
+            if let Some(auction) = Auctions::<T, I>::take(commodity_id) {
+                Self::settle_auction(&commodity_id, auction);
+            } else if DutchAuctions::<T, I>::take(commodity_id).is_some() {
+                Self::deposit_event(RawEvent::AuctionEnded(commodity_id));
+            }
+        }
+    }
+
+    /// Sell the commodity to the highest bidder, if there is one. The highest bid is refunded if
+    /// the commodity is no longer owned by the seller or if the sale fails.
+    fn settle_auction(commodity_id: &CommodityId<T>, auction: AuctionOf<T, I>) {
+        Self::deposit_event(RawEvent::AuctionEnded(*commodity_id));
+
+        if let Some((bidder, bid)) = auction.highest_bid {
+            T::Currency::unreserve(&bidder, bid);
+            if Self::account_for_commodity(commodity_id) == auction.seller
+                && Self::sell(&auction.seller, &bidder, commodity_id, bid).is_ok()
+            {
+                Self::deposit_event(RawEvent::Sold(*commodity_id, auction.seller, bidder, bid));
+            }
+        }
+    }
+}
@@ -0,0 +1,688 @@
+//! Benchmarks for the commodities pallet.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks_instance};
+use frame_support::traits::{Currency, OnFinalize, OnInitialize, UnfilteredDispatchable};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+/// The largest number of commodities owned by a single account that the benchmarks consider.
+const MAX_OWNED: u32 = 1_000;
+
+/// The largest number of commodities that a single account may own, limited by `MAX_OWNED`.
+fn owned_limit<T: Trait<I>, I: Instance>() -> u32 {
+    T::UserCommodityLimit::get()
+        .min(T::CommodityLimit::get().saturated_into())
+        .min(MAX_OWNED.into())
+        .saturated_into()
+}
+
+/// Commodity info that is unique to the account and index.
+fn commodity_info<T: Trait<I>, I: Instance>(owner: &T::AccountId, index: u32) -> T::CommodityInfo
+where
+    T::CommodityInfo: From<Vec<u8>>,
+{
+    (owner, index).encode().into()
+}
+
+/// An origin that may manage the attributes of a commodity owned by the account.
+fn attribute_origin<T: Trait<I>, I: Instance>(owner: T::AccountId) -> T::Origin {
+    match T::AttributePolicy::get() {
+        AttributePolicy::Admin => T::CommodityAdmin::successful_origin(),
+        _ => RawOrigin::Signed(owner).into(),
+    }
+}
+
+/// Give an account a balance that is large enough to buy anything in the benchmarks.
+fn fund<T: Trait<I>, I: Instance>(account: &T::AccountId) {
+    T::Currency::make_free_balance_be(account, BalanceOf::<T, I>::max_value() / 2u32.into());
+}
+
+/// Fund an account and mint a number of commodities for it, returning the ID of the last one.
+fn mint_commodities<T: Trait<I>, I: Instance>(owner: &T::AccountId, count: u32) -> CommodityId<T>
+where
+    T::CommodityInfo: From<Vec<u8>>,
+{
+    fund::<T, I>(owner);
+    let mut commodity_id = CommodityId::<T>::default();
+    for index in 0..count {
+        commodity_id =
+            <Module<T, I> as UniqueAssets<_>>::mint(owner, commodity_info::<T, I>(owner, index))
+                .expect("The number of commodities is within the configured limits; qed");
+    }
+
+    commodity_id
+}
+
+/// Give a commodity the maximum number of attributes, which is the most expensive case for burning it.
+fn add_attributes<T: Trait<I>, I: Instance>(commodity_id: &CommodityId<T>) {
+    let max_attributes = T::MaxAttributes::get();
+    for index in 0..max_attributes {
+        Attributes::<T, I>::insert(
+            commodity_id,
+            index.encode(),
+            vec![0; T::ValueLimit::get() as usize],
+        );
+    }
+    AttributeCount::<T, I>::insert(commodity_id, max_attributes);
+}
+
+benchmarks_instance! {
+    where_clause {
+        where
+            T::CommodityInfo: From<Vec<u8>>,
+            <T::SwapAssets as UniqueAssets<T::AccountId>>::AssetInfo: From<Vec<u8>>,
+    }
+
+    _ { }
+
+    // Minting with an allowance is the most expensive way to mint.
+    mint {
+        let c in 0 .. owned_limit::<T, I>().saturating_sub(1);
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let minter: T::AccountId = account("minter", 0, SEED);
+        mint_commodities::<T, I>(&owner, c);
+        fund::<T, I>(&minter);
+        MintAllowance::<T, I>::insert(&minter, 1);
+        let commodity_info = commodity_info::<T, I>(&owner, c);
+        let deposit = Module::<T, I>::deposit_for(&commodity_info);
+    }: _(RawOrigin::Signed(minter.clone()), owner.clone(), commodity_info)
+    verify {
+        assert_eq!(Module::<T, I>::total_for_account(&owner), c as u64 + 1);
+        assert_eq!(Module::<T, I>::mint_allowance(&minter), 0);
+        assert_eq!(T::Currency::reserved_balance(&minter), deposit);
+    }
+
+    burn {
+        let c in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, c);
+        add_attributes::<T, I>(&commodity_id);
+    }: _(RawOrigin::Signed(owner.clone()), commodity_id)
+    verify {
+        assert_eq!(Module::<T, I>::total_for_account(&owner), c as u64 - 1);
+    }
+
+    transfer {
+        let c in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, c);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        fund::<T, I>(&dest);
+    }: _(RawOrigin::Signed(owner), dest.clone(), commodity_id)
+    verify {
+        assert_eq!(Module::<T, I>::account_for_commodity(commodity_id), dest);
+    }
+
+    approve {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        let approved: T::AccountId = account("approved", 0, SEED);
+    }: _(RawOrigin::Signed(owner), approved.clone(), commodity_id)
+    verify {
+        assert_eq!(Module::<T, I>::approved_for(commodity_id), Some(approved));
+    }
+
+    clear_approval {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        let approved: T::AccountId = account("approved", 0, SEED);
+        Approved::<T, I>::insert(commodity_id, approved);
+    }: _(RawOrigin::Signed(owner), commodity_id)
+    verify {
+        assert_eq!(Module::<T, I>::approved_for(commodity_id), None);
+    }
+
+    transfer_from {
+        let c in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, c);
+        let approved: T::AccountId = account("approved", 0, SEED);
+        Approved::<T, I>::insert(commodity_id, approved.clone());
+        let dest: T::AccountId = account("dest", 0, SEED);
+        fund::<T, I>(&dest);
+    }: _(RawOrigin::Signed(approved), dest.clone(), commodity_id)
+    verify {
+        assert_eq!(Module::<T, I>::account_for_commodity(commodity_id), dest);
+    }
+
+    safe_transfer {
+        let c in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, c);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        fund::<T, I>(&dest);
+    }: _(RawOrigin::Signed(owner), dest.clone(), commodity_id, Vec::new())
+    verify {
+        assert_eq!(Module::<T, I>::account_for_commodity(commodity_id), dest);
+    }
+
+    set_operator {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let operator: T::AccountId = account("operator", 0, SEED);
+    }: _(RawOrigin::Signed(owner.clone()), operator.clone())
+    verify {
+        assert!(Module::<T, I>::is_operator(owner, operator));
+    }
+
+    revoke_operator {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let operator: T::AccountId = account("operator", 0, SEED);
+        Operators::<T, I>::insert(&owner, &operator, true);
+    }: _(RawOrigin::Signed(owner.clone()), operator.clone())
+    verify {
+        assert!(!Module::<T, I>::is_operator(owner, operator));
+    }
+
+    mint_batch {
+        let n in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        fund::<T, I>(&owner);
+        let commodities = (0..n)
+            .map(|index| (owner.clone(), commodity_info::<T, I>(&owner, index)))
+            .collect::<Vec<_>>();
+        let origin = T::CommodityAdmin::successful_origin();
+        let call = Call::<T, I>::mint_batch(commodities);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T, I>::total_for_account(&owner), n as u64);
+    }
+
+    burn_batch {
+        let n in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        mint_commodities::<T, I>(&owner, n);
+        let commodity_ids = CommoditiesForAccount::<T, I>::iter_prefix(&owner)
+            .map(|(commodity_id, _)| commodity_id)
+            .collect::<Vec<_>>();
+        commodity_ids.iter().for_each(add_attributes::<T, I>);
+    }: _(RawOrigin::Signed(owner.clone()), commodity_ids)
+    verify {
+        assert_eq!(Module::<T, I>::total_for_account(&owner), 0);
+    }
+
+    transfer_batch {
+        let n in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        mint_commodities::<T, I>(&owner, n);
+        let commodity_ids = CommoditiesForAccount::<T, I>::iter_prefix(&owner)
+            .map(|(commodity_id, _)| commodity_id)
+            .collect::<Vec<_>>();
+        let dest: T::AccountId = account("dest", 0, SEED);
+        fund::<T, I>(&dest);
+    }: _(RawOrigin::Signed(owner.clone()), dest.clone(), commodity_ids)
+    verify {
+        assert_eq!(Module::<T, I>::total_for_account(&dest), n as u64);
+    }
+
+    set_attribute {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        AttributeCount::<T, I>::insert(commodity_id, T::MaxAttributes::get().saturating_sub(1));
+        let key = vec![0; T::KeyLimit::get() as usize];
+        let value = vec![0; T::ValueLimit::get() as usize];
+        let origin = attribute_origin::<T, I>(owner);
+        let call = Call::<T, I>::set_attribute(commodity_id, key.clone(), value.clone());
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T, I>::attribute(commodity_id, key), Some(value));
+    }
+
+    clear_attribute {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        let key = vec![0; T::KeyLimit::get() as usize];
+        Attributes::<T, I>::insert(commodity_id, &key, vec![0; T::ValueLimit::get() as usize]);
+        AttributeCount::<T, I>::insert(commodity_id, 1);
+        let origin = attribute_origin::<T, I>(owner);
+        let call = Call::<T, I>::clear_attribute(commodity_id, key.clone());
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T, I>::attribute(commodity_id, key), None);
+    }
+
+    mint_with_royalty {
+        let c in 0 .. owned_limit::<T, I>().saturating_sub(1);
+        let owner: T::AccountId = account("owner", 0, SEED);
+        mint_commodities::<T, I>(&owner, c);
+        let commodity_info = commodity_info::<T, I>(&owner, c);
+        let royalty_account: T::AccountId = account("royalty", 0, SEED);
+        let origin = T::CommodityAdmin::successful_origin();
+        let call = Call::<T, I>::mint_with_royalty(
+            owner.clone(),
+            commodity_info,
+            royalty_account,
+            Perbill::from_percent(10),
+        );
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T, I>::total_for_account(&owner), c as u64 + 1);
+    }
+
+    list_for_sale {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        let price = T::Currency::minimum_balance();
+    }: _(RawOrigin::Signed(owner), commodity_id, price)
+    verify {
+        assert_eq!(Module::<T, I>::listing(commodity_id), Some(price));
+    }
+
+    cancel_listing {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        Listings::<T, I>::insert(commodity_id, T::Currency::minimum_balance());
+    }: _(RawOrigin::Signed(owner), commodity_id)
+    verify {
+        assert_eq!(Module::<T, I>::listing(commodity_id), None);
+    }
+
+    buy {
+        let c in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, c);
+        let royalty_account: T::AccountId = account("royalty", 0, SEED);
+        Royalties::<T, I>::insert(commodity_id, (royalty_account, Perbill::from_percent(10)));
+        // Buying a commodity from a Dutch auction is more expensive than buying a listed one.
+        let price = T::Currency::minimum_balance() * 100u32.into();
+        let end = frame_system::Module::<T>::block_number() + 10u32.into();
+        Module::<T, I>::open_dutch_auction(&owner, &commodity_id, price, price, end)?;
+        let buyer: T::AccountId = account("buyer", 0, SEED);
+        fund::<T, I>(&buyer);
+    }: _(RawOrigin::Signed(buyer.clone()), commodity_id, price)
+    verify {
+        assert_eq!(Module::<T, I>::account_for_commodity(commodity_id), buyer);
+    }
+
+    start_auction {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        Listings::<T, I>::insert(commodity_id, T::Currency::minimum_balance());
+        let reserve_price = T::Currency::minimum_balance();
+        let end = frame_system::Module::<T>::block_number() + 10u32.into();
+    }: _(RawOrigin::Signed(owner), commodity_id, reserve_price, end)
+    verify {
+        assert!(Module::<T, I>::auction(commodity_id).is_some());
+    }
+
+    bid {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        let reserve_price = T::Currency::minimum_balance();
+        let end = frame_system::Module::<T>::block_number() + 10u32.into();
+        Module::<T, I>::open_english_auction(&owner, &commodity_id, reserve_price, end)?;
+        let previous_bidder: T::AccountId = account("bidder", 0, SEED);
+        fund::<T, I>(&previous_bidder);
+        Module::<T, I>::place_bid(&previous_bidder, &commodity_id, reserve_price)?;
+        let bidder: T::AccountId = account("bidder", 1, SEED);
+        fund::<T, I>(&bidder);
+        let amount = reserve_price * 2u32.into();
+    }: _(RawOrigin::Signed(bidder.clone()), commodity_id, amount)
+    verify {
+        assert_eq!(
+            Module::<T, I>::auction(commodity_id).and_then(|auction| auction.highest_bid),
+            Some((bidder, amount))
+        );
+    }
+
+    start_dutch_auction {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        Listings::<T, I>::insert(commodity_id, T::Currency::minimum_balance());
+        let start_price = T::Currency::minimum_balance() * 100u32.into();
+        let floor_price = T::Currency::minimum_balance();
+        let end = frame_system::Module::<T>::block_number() + 10u32.into();
+    }: _(RawOrigin::Signed(owner), commodity_id, start_price, floor_price, end)
+    verify {
+        assert!(Module::<T, I>::dutch_auction(commodity_id).is_some());
+    }
+
+    settle_auctions {
+        let n in 1 .. T::MaxAuctionsEndingPerBlock::get();
+        let end = frame_system::Module::<T>::block_number() + 10u32.into();
+        let mut commodity_ids = Vec::new();
+        for index in 0..n {
+            let owner: T::AccountId = account("owner", index, SEED);
+            let commodity_id = mint_commodities::<T, I>(&owner, 1);
+            let royalty_account: T::AccountId = account("royalty", index, SEED);
+            Royalties::<T, I>::insert(commodity_id, (royalty_account, Perbill::from_percent(10)));
+            let reserve_price = T::Currency::minimum_balance() * 100u32.into();
+            Module::<T, I>::open_english_auction(&owner, &commodity_id, reserve_price, end)?;
+            let bidder: T::AccountId = account("bidder", index, SEED);
+            fund::<T, I>(&bidder);
+            Module::<T, I>::place_bid(&bidder, &commodity_id, reserve_price)?;
+            commodity_ids.push(commodity_id);
+        }
+    }: { Module::<T, I>::on_finalize(end); }
+    verify {
+        for (index, commodity_id) in commodity_ids.into_iter().enumerate() {
+            let bidder: T::AccountId = account("bidder", index as u32, SEED);
+            assert_eq!(Module::<T, I>::account_for_commodity(commodity_id), bidder);
+        }
+    }
+
+    make_offer {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        let bidder: T::AccountId = account("bidder", 0, SEED);
+        fund::<T, I>(&bidder);
+        // Replacing a previous offer is more expensive than making a first offer.
+        let amount = T::Currency::minimum_balance() * 100u32.into();
+        let expiry = frame_system::Module::<T>::block_number() + 10u32.into();
+        Module::<T, I>::place_offer(&bidder, &commodity_id, amount, expiry)?;
+    }: _(RawOrigin::Signed(bidder.clone()), commodity_id, amount * 2u32.into(), expiry + 1u32.into())
+    verify {
+        assert_eq!(
+            Module::<T, I>::offer(commodity_id, &bidder).map(|offer| offer.amount),
+            Some(amount * 2u32.into())
+        );
+    }
+
+    withdraw_offer {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        let bidder: T::AccountId = account("bidder", 0, SEED);
+        fund::<T, I>(&bidder);
+        let amount = T::Currency::minimum_balance() * 100u32.into();
+        let expiry = frame_system::Module::<T>::block_number() + 10u32.into();
+        Module::<T, I>::place_offer(&bidder, &commodity_id, amount, expiry)?;
+    }: _(RawOrigin::Signed(bidder.clone()), commodity_id)
+    verify {
+        assert!(Module::<T, I>::offer(commodity_id, &bidder).is_none());
+    }
+
+    accept_offer {
+        let c in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, c);
+        let royalty_account: T::AccountId = account("royalty", 0, SEED);
+        Royalties::<T, I>::insert(commodity_id, (royalty_account, Perbill::from_percent(10)));
+        let bidder: T::AccountId = account("bidder", 0, SEED);
+        fund::<T, I>(&bidder);
+        let amount = T::Currency::minimum_balance() * 100u32.into();
+        let expiry = frame_system::Module::<T>::block_number() + 10u32.into();
+        Module::<T, I>::place_offer(&bidder, &commodity_id, amount, expiry)?;
+    }: _(RawOrigin::Signed(owner), commodity_id, bidder.clone())
+    verify {
+        assert_eq!(Module::<T, I>::account_for_commodity(commodity_id), bidder);
+    }
+
+    prune_offers {
+        let n in 1 .. T::MaxOffersExpiringPerBlock::get();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        let expiry = frame_system::Module::<T>::block_number() + 10u32.into();
+        for index in 0..n {
+            let bidder: T::AccountId = account("bidder", index, SEED);
+            fund::<T, I>(&bidder);
+            let amount = T::Currency::minimum_balance() * 100u32.into();
+            Module::<T, I>::place_offer(&bidder, &commodity_id, amount, expiry)?;
+        }
+    }: { Module::<T, I>::on_initialize(expiry); }
+    verify {
+        assert!(Module::<T, I>::offers_expiring_at(expiry).is_empty());
+    }
+
+    propose_swap {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        let wanted = CommodityId::<T>::default();
+        let payment = T::Currency::minimum_balance();
+    }: _(RawOrigin::Signed(owner), commodity_id, wanted, payment)
+    verify {
+        assert!(Module::<T, I>::swap(commodity_id).is_some());
+    }
+
+    cancel_swap {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, 1);
+        let swap = Swap {
+            proposer: owner.clone(),
+            wanted: CommodityId::<T>::default(),
+            payment: T::Currency::minimum_balance(),
+        };
+        Swaps::<T, I>::insert(commodity_id, swap);
+    }: _(RawOrigin::Signed(owner), commodity_id)
+    verify {
+        assert!(Module::<T, I>::swap(commodity_id).is_none());
+    }
+
+    accept_swap {
+        let c in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, c);
+        fund::<T, I>(&owner);
+        let acceptor: T::AccountId = account("acceptor", 0, SEED);
+        fund::<T, I>(&acceptor);
+        let wanted = T::SwapAssets::mint(&acceptor, (&acceptor, 0u32).encode().into())?;
+        let payment = T::Currency::minimum_balance() * 100u32.into();
+        let swap = Swap { proposer: owner.clone(), wanted, payment };
+        Swaps::<T, I>::insert(commodity_id, swap);
+    }: _(RawOrigin::Signed(acceptor.clone()), commodity_id, payment)
+    verify {
+        assert_eq!(Module::<T, I>::account_for_commodity(commodity_id), acceptor);
+        assert_eq!(T::SwapAssets::owner_of(&wanted), owner);
+    }
+
+    recover {
+        let c in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, c);
+        let dest_account: T::AccountId = account("dest", 0, SEED);
+        fund::<T, I>(&dest_account);
+        let origin = T::CommodityAdmin::successful_origin();
+        let call = Call::<T, I>::recover(dest_account.clone(), commodity_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T, I>::account_for_commodity(commodity_id), dest_account);
+    }
+
+    force_transfer {
+        let c in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, c);
+        let dest_account: T::AccountId = account("dest", 0, SEED);
+        fund::<T, I>(&dest_account);
+        let origin = T::ForceOrigin::successful_origin();
+        let call = Call::<T, I>::force_transfer(owner, dest_account.clone(), commodity_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T, I>::account_for_commodity(commodity_id), dest_account);
+    }
+
+    force_burn {
+        let c in 1 .. owned_limit::<T, I>();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let commodity_id = mint_commodities::<T, I>(&owner, c);
+        add_attributes::<T, I>(&commodity_id);
+        let origin = T::ForceOrigin::successful_origin();
+        let call = Call::<T, I>::force_burn(commodity_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T, I>::total_for_account(&owner), c as u64 - 1);
+    }
+
+    pause {
+        let origin = T::ForceOrigin::successful_origin();
+        let call = Call::<T, I>::pause(None);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(Module::<T, I>::paused(None::<Operation>));
+    }
+
+    unpause {
+        Paused::<I>::insert(None::<Operation>, true);
+        let origin = T::ForceOrigin::successful_origin();
+        let call = Call::<T, I>::unpause(None);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(!Module::<T, I>::paused(None::<Operation>));
+    }
+
+    create_collection {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        fund::<T, I>(&owner);
+        let metadata = vec![0u8; T::MetadataLimit::get() as usize];
+    }: _(RawOrigin::Signed(owner.clone()), u128::MAX, u64::MAX, metadata)
+    verify {
+        assert_eq!(Module::<T, I>::collection(Module::<T, I>::collection_count()).map(|c| c.owner), Some(owner));
+    }
+
+    force_create_collection {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let metadata = vec![0u8; T::MetadataLimit::get() as usize];
+        let origin = T::CommodityAdmin::successful_origin();
+        let call = Call::<T, I>::force_create_collection(owner.clone(), u128::MAX, u64::MAX, metadata);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T, I>::collection(Module::<T, I>::collection_count()).map(|c| c.owner), Some(owner));
+    }
+
+    destroy_collection {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        fund::<T, I>(&owner);
+        let collection_id = <Module<T, I> as UniqueCollections<_>>::create_collection(&owner, u128::MAX, u64::MAX, Vec::new())?;
+    }: _(RawOrigin::Signed(owner), collection_id)
+    verify {
+        assert!(Module::<T, I>::collection(collection_id).is_none());
+    }
+
+    mint_into {
+        let c in 0 .. MAX_OWNED - 1;
+        let admin: T::AccountId = account("admin", 0, SEED);
+        let owner: T::AccountId = account("owner", 0, SEED);
+        fund::<T, I>(&admin);
+        fund::<T, I>(&owner);
+        let collection_id = <Module<T, I> as UniqueCollections<_>>::create_collection(&admin, u128::MAX, u64::MAX, Vec::new())?;
+        for index in 0..c {
+            <Module<T, I> as UniqueCollections<_>>::mint_into(&collection_id, &owner, commodity_info::<T, I>(&owner, index))?;
+        }
+        let commodity_info = commodity_info::<T, I>(&owner, c);
+    }: _(RawOrigin::Signed(admin), collection_id, owner.clone(), commodity_info)
+    verify {
+        assert_eq!(Module::<T, I>::total_in_collection_for_account(collection_id, &owner), c as u64 + 1);
+    }
+
+    set_team {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let member: T::AccountId = account("member", 0, SEED);
+        fund::<T, I>(&owner);
+        let collection_id = <Module<T, I> as UniqueCollections<_>>::create_collection(&owner, u128::MAX, u64::MAX, Vec::new())?;
+    }: _(RawOrigin::Signed(owner), collection_id, member.clone(), member.clone(), member.clone())
+    verify {
+        assert_eq!(Module::<T, I>::team(collection_id).map(|team| team.freezer), Some(member));
+    }
+
+    transfer_ownership {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let new_owner: T::AccountId = account("new_owner", 0, SEED);
+        fund::<T, I>(&owner);
+        fund::<T, I>(&new_owner);
+        let collection_id = <Module<T, I> as UniqueCollections<_>>::create_collection(&owner, u128::MAX, u64::MAX, Vec::new())?;
+    }: _(RawOrigin::Signed(owner), collection_id, new_owner.clone())
+    verify {
+        assert_eq!(Module::<T, I>::collection(collection_id).map(|c| c.owner), Some(new_owner));
+    }
+
+    freeze {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        fund::<T, I>(&owner);
+        let collection_id = <Module<T, I> as UniqueCollections<_>>::create_collection(&owner, u128::MAX, u64::MAX, Vec::new())?;
+        let commodity_id = <Module<T, I> as UniqueCollections<_>>::mint_into(&collection_id, &owner, commodity_info::<T, I>(&owner, 0))?;
+    }: _(RawOrigin::Signed(owner), commodity_id)
+    verify {
+        assert!(<Module<T, I> as LockableUniqueAssets<_>>::is_locked(&commodity_id));
+    }
+
+    thaw {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        fund::<T, I>(&owner);
+        let collection_id = <Module<T, I> as UniqueCollections<_>>::create_collection(&owner, u128::MAX, u64::MAX, Vec::new())?;
+        let commodity_id = <Module<T, I> as UniqueCollections<_>>::mint_into(&collection_id, &owner, commodity_info::<T, I>(&owner, 0))?;
+        <Module<T, I> as LockableUniqueAssets<_>>::lock(&commodity_id, FREEZE_LOCK)?;
+    }: _(RawOrigin::Signed(owner), commodity_id)
+    verify {
+        assert!(!<Module<T, I> as LockableUniqueAssets<_>>::is_locked(&commodity_id));
+    }
+
+    grant_mint_allowance {
+        let minter: T::AccountId = account("minter", 0, SEED);
+        let origin = T::CommodityAdmin::successful_origin();
+        let call = Call::<T, I>::grant_mint_allowance(minter.clone(), 10);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T, I>::mint_allowance(&minter), 10);
+    }
+
+    revoke_mint_allowance {
+        let minter: T::AccountId = account("minter", 0, SEED);
+        MintAllowance::<T, I>::insert(&minter, 10);
+        let origin = T::CommodityAdmin::successful_origin();
+        let call = Call::<T, I>::revoke_mint_allowance(minter.clone());
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T, I>::mint_allowance(&minter), 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{new_test_ext, set_deposits, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn test_benchmarks() {
+        new_test_ext().execute_with(|| {
+            set_deposits(10, 1);
+            assert_ok!(test_benchmark_mint::<Test>());
+            assert_ok!(test_benchmark_burn::<Test>());
+            assert_ok!(test_benchmark_transfer::<Test>());
+            assert_ok!(test_benchmark_approve::<Test>());
+            assert_ok!(test_benchmark_clear_approval::<Test>());
+            assert_ok!(test_benchmark_transfer_from::<Test>());
+            assert_ok!(test_benchmark_safe_transfer::<Test>());
+            assert_ok!(test_benchmark_set_operator::<Test>());
+            assert_ok!(test_benchmark_revoke_operator::<Test>());
+            assert_ok!(test_benchmark_mint_batch::<Test>());
+            assert_ok!(test_benchmark_burn_batch::<Test>());
+            assert_ok!(test_benchmark_transfer_batch::<Test>());
+            assert_ok!(test_benchmark_set_attribute::<Test>());
+            assert_ok!(test_benchmark_clear_attribute::<Test>());
+            assert_ok!(test_benchmark_mint_with_royalty::<Test>());
+            assert_ok!(test_benchmark_list_for_sale::<Test>());
+            assert_ok!(test_benchmark_cancel_listing::<Test>());
+            assert_ok!(test_benchmark_buy::<Test>());
+            assert_ok!(test_benchmark_start_auction::<Test>());
+            assert_ok!(test_benchmark_bid::<Test>());
+            assert_ok!(test_benchmark_start_dutch_auction::<Test>());
+            assert_ok!(test_benchmark_settle_auctions::<Test>());
+            assert_ok!(test_benchmark_make_offer::<Test>());
+            assert_ok!(test_benchmark_withdraw_offer::<Test>());
+            assert_ok!(test_benchmark_accept_offer::<Test>());
+            assert_ok!(test_benchmark_prune_offers::<Test>());
+            assert_ok!(test_benchmark_propose_swap::<Test>());
+            assert_ok!(test_benchmark_cancel_swap::<Test>());
+            assert_ok!(test_benchmark_accept_swap::<Test>());
+            assert_ok!(test_benchmark_recover::<Test>());
+            assert_ok!(test_benchmark_force_transfer::<Test>());
+            assert_ok!(test_benchmark_force_burn::<Test>());
+            assert_ok!(test_benchmark_pause::<Test>());
+            assert_ok!(test_benchmark_unpause::<Test>());
+            assert_ok!(test_benchmark_create_collection::<Test>());
+            assert_ok!(test_benchmark_force_create_collection::<Test>());
+            assert_ok!(test_benchmark_destroy_collection::<Test>());
+            assert_ok!(test_benchmark_mint_into::<Test>());
+            assert_ok!(test_benchmark_set_team::<Test>());
+            assert_ok!(test_benchmark_transfer_ownership::<Test>());
+            assert_ok!(test_benchmark_freeze::<Test>());
+            assert_ok!(test_benchmark_thaw::<Test>());
+            assert_ok!(test_benchmark_grant_mint_allowance::<Test>());
+            assert_ok!(test_benchmark_revoke_mint_allowance::<Test>());
+        });
+    }
+}
@@ -0,0 +1,240 @@
+//! Collections of commodities.
+//!
+//! Every commodity belongs to a collection within its pallet instance, which allows many kinds of
+//! commodity to share a single instance. The default collection always exists: it is managed by
+//! the commodity admin and is limited by the `CommodityLimit` and `UserCommodityLimit` parameters
+//! of the pallet's configuration trait. Any account may create additional collections, each with
+//! its own limits and metadata, by reserving the `CollectionDeposit`. The owner of a collection
+//! may destroy it, releasing the deposit, once all of its commodities have been burned.
+//!
+//! Each collection is managed by a team of accounts that hold distinct roles: the issuer may mint
+//! commodities in the collection, the admin may transfer and burn them without the consent of
+//! their owners and the freezer may freeze and thaw them. The owner of a collection, or the
+//! commodity admin in the case of the default collection, appoints the team. The commodity admin
+//! may also mint commodities in the default collection and the force origin may act as the admin
+//! or freezer of any collection.
+//!
+//! The ID of a commodity in a collection other than the default collection is generated from its
+//! collection ID as well as its info, so that commodities with equivalent info (e.g. each creator's
+//! "ticket #1") may exist in different collections. The data from which the ID of a commodity in
+//! the default collection is generated is tagged differently from that of every other collection,
+//! so the info of one commodity may not be chosen to reproduce the ID of a commodity in another
+//! collection. Commodity IDs are therefore distinct across collections, so commodities continue to
+//! be identified by their IDs alone, and the commodities in each collection are indexed by
+//! collection ID and commodity ID.
+
+use super::*;
+use sp_runtime::traits::{CheckedAdd, One};
+
+/// The lock that is held on commodities that have been frozen by the freezer of their collection.
+pub const FREEZE_LOCK: LockIdentifier = *b"freezer ";
+
+/// A collection of commodities.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Collection<AccountId, Balance> {
+    /// The account that owns the collection, which may appoint its team and destroy it.
+    pub owner: AccountId,
+    /// The amount that is reserved from the owner for as long as the collection exists.
+    pub deposit: Balance,
+    /// The maximum number of commodities that may exist in the collection (minted - burned).
+    pub max_commodities: u128,
+    /// The maximum number of commodities in the collection that any single account may own.
+    pub max_commodities_per_account: u64,
+    /// Arbitrary data that describes the collection.
+    pub metadata: Vec<u8>,
+}
+
+/// A collection of commodities of this pallet.
+pub type CollectionOf<T, I> = Collection<<T as frame_system::Trait>::AccountId, BalanceOf<T, I>>;
+
+/// The accounts that manage a collection.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Team<AccountId> {
+    /// The account that may mint commodities in the collection.
+    pub issuer: AccountId,
+    /// The account that may transfer and burn commodities in the collection without the consent
+    /// of their owners.
+    pub admin: AccountId,
+    /// The account that may freeze and thaw commodities in the collection.
+    pub freezer: AccountId,
+}
+
+/// The roles of the team that manages a collection.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Role {
+    /// May mint commodities.
+    Issuer,
+    /// May transfer and burn commodities without the consent of their owners.
+    Admin,
+    /// May freeze and thaw commodities.
+    Freezer,
+}
+
+impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// Create a collection with the next available collection ID that is owned by an account,
+    /// which also holds every role of its team, reserving the deposit from that account.
+    pub(crate) fn open_collection(
+        owner: &T::AccountId,
+        deposit: BalanceOf<T, I>,
+        max_commodities: u128,
+        max_commodities_per_account: u64,
+        metadata: Vec<u8>,
+    ) -> Result<T::CollectionId, dispatch::DispatchError> {
+        ensure!(
+            metadata.len() <= T::MetadataLimit::get() as usize,
+            Error::<T, I>::MetadataTooLong
+        );
+
+        // The default collection is not created, so the first collection has an ID of one.
+        let collection_id = Self::collection_count()
+            .checked_add(&One::one())
+            .ok_or(Error::<T, I>::NoAvailableCollectionId)?;
+        T::Currency::reserve(owner, deposit)?;
+
+        CollectionCount::<T, I>::put(collection_id);
+        Collections::<T, I>::insert(
+            collection_id,
+            Collection {
+                owner: owner.clone(),
+                deposit,
+                max_commodities,
+                max_commodities_per_account,
+                metadata,
+            },
+        );
+        Teams::<T, I>::insert(
+            collection_id,
+            Team {
+                issuer: owner.clone(),
+                admin: owner.clone(),
+                freezer: owner.clone(),
+            },
+        );
+
+        Ok(collection_id)
+    }
+
+    /// Destroy a collection that has no commodities, releasing its deposit.
+    pub(crate) fn close_collection(collection_id: &T::CollectionId) -> DispatchResult {
+        let collection =
+            Self::collection(collection_id).ok_or(Error::<T, I>::NonexistentCollection)?;
+        ensure!(
+            Self::total_in_collection(collection_id) == 0,
+            Error::<T, I>::CollectionNotEmpty
+        );
+
+        T::Currency::unreserve(&collection.owner, collection.deposit);
+        Collections::<T, I>::remove(collection_id);
+        Teams::<T, I>::remove(collection_id);
+        TotalInCollection::<T, I>::remove(collection_id);
+        TotalInCollectionForAccount::<T, I>::remove_prefix(collection_id);
+
+        Ok(())
+    }
+
+    /// The maximum number of commodities that may exist in a collection and the maximum number
+    /// that any single account may own, if the collection exists.
+    pub(crate) fn collection_limits(collection_id: &T::CollectionId) -> Option<(u128, u64)> {
+        if collection_id.is_zero() {
+            return Some((T::CommodityLimit::get(), T::UserCommodityLimit::get()));
+        }
+
+        Self::collection(collection_id).map(|collection| {
+            (
+                collection.max_commodities,
+                collection.max_commodities_per_account,
+            )
+        })
+    }
+
+    /// Transfer ownership of a collection, and the deposit that is reserved for it, to another
+    /// account.
+    pub(crate) fn reassign_collection(
+        collection_id: &T::CollectionId,
+        dest_account: &T::AccountId,
+    ) -> DispatchResult {
+        let collection =
+            Self::collection(collection_id).ok_or(Error::<T, I>::NonexistentCollection)?;
+
+        T::Currency::repatriate_reserved(
+            &collection.owner,
+            dest_account,
+            collection.deposit,
+            BalanceStatus::Reserved,
+        )?;
+        Collections::<T, I>::insert(
+            collection_id,
+            Collection {
+                owner: dest_account.clone(),
+                ..collection
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Ensure that the origin holds a role in the team of a collection. The commodity admin holds
+    /// the issuer role of the default collection and the force origin holds the admin and freezer
+    /// roles of every collection.
+    pub(crate) fn ensure_role(
+        origin: T::Origin,
+        collection_id: &T::CollectionId,
+        role: Role,
+    ) -> DispatchResult {
+        let origin = match role {
+            Role::Issuer if collection_id.is_zero() => {
+                match T::CommodityAdmin::try_origin(origin) {
+                    Ok(_) => return Ok(()),
+                    Err(origin) => origin,
+                }
+            }
+            Role::Issuer => origin,
+            Role::Admin | Role::Freezer => match T::ForceOrigin::try_origin(origin) {
+                Ok(_) => return Ok(()),
+                Err(origin) => origin,
+            },
+        };
+
+        let who = ensure_signed(origin)?;
+        let team = match Self::team(collection_id) {
+            Some(team) => team,
+            // Only the fallback origins may manage the default collection until its team has been
+            // appointed.
+            None if collection_id.is_zero() => return Err(dispatch::DispatchError::BadOrigin),
+            None => return Err(Error::<T, I>::NonexistentCollection.into()),
+        };
+        let member = match role {
+            Role::Issuer => team.issuer,
+            Role::Admin => team.admin,
+            Role::Freezer => team.freezer,
+        };
+        ensure!(member == who, Error::<T, I>::NoPermission);
+        Ok(())
+    }
+
+    /// Ensure that the origin may appoint the team of a collection, which is the owner of the
+    /// collection or the commodity admin in the case of the default collection.
+    pub(crate) fn ensure_team_appointer(
+        origin: T::Origin,
+        collection_id: &T::CollectionId,
+    ) -> DispatchResult {
+        if collection_id.is_zero() {
+            T::CommodityAdmin::ensure_origin(origin)?;
+            return Ok(());
+        }
+
+        let who = ensure_signed(origin)?;
+        Self::ensure_collection_owner(&who, collection_id)
+    }
+
+    /// Ensure that an account is the owner of an existing collection.
+    pub(crate) fn ensure_collection_owner(
+        who: &T::AccountId,
+        collection_id: &T::CollectionId,
+    ) -> DispatchResult {
+        let collection =
+            Self::collection(collection_id).ok_or(Error::<T, I>::NonexistentCollection)?;
+        ensure!(collection.owner == *who, Error::<T, I>::NotCollectionOwner);
+        Ok(())
+    }
+}
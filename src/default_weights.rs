@@ -0,0 +1,237 @@
+//! Default weights for the commodities pallet.
+//!
+//! These weights are PLACEHOLDERS: they are estimated by hand from the number of storage reads and
+//! writes of each dispatchable function and have not been generated by running the benchmarks.
+//! Runtimes must generate weights for their own hardware and configuration by running the
+//! benchmarks that are defined in `benchmarking.rs`. Since commodity ownership is stored in a
+//! double map, the cost of operating on a commodity does not depend on the number of commodities
+//! owned by the account (`c`), so the placeholders ignore that parameter.
+
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+impl crate::WeightInfo for () {
+    fn mint(_c: u32) -> Weight {
+        (47_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn burn(_c: u32) -> Weight {
+        (52_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(8 as Weight))
+            .saturating_add(DbWeight::get().writes(14 as Weight))
+    }
+    fn transfer(_c: u32) -> Weight {
+        (55_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(10 as Weight))
+            .saturating_add(DbWeight::get().writes(11 as Weight))
+    }
+    fn approve() -> Weight {
+        (24_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn clear_approval() -> Weight {
+        (23_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn transfer_from(_c: u32) -> Weight {
+        (58_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(11 as Weight))
+            .saturating_add(DbWeight::get().writes(11 as Weight))
+    }
+    fn safe_transfer(_c: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(12 as Weight))
+            .saturating_add(DbWeight::get().writes(11 as Weight))
+    }
+    fn set_operator() -> Weight {
+        (17_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn revoke_operator() -> Weight {
+        (17_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn mint_batch(n: u32) -> Weight {
+        (12_000_000 as Weight)
+            .saturating_add((45_000_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add(DbWeight::get().reads((8 as Weight).saturating_mul(n as Weight)))
+            .saturating_add(DbWeight::get().writes((11 as Weight).saturating_mul(n as Weight)))
+    }
+    fn burn_batch(n: u32) -> Weight {
+        (12_000_000 as Weight)
+            .saturating_add((50_000_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add(DbWeight::get().reads((8 as Weight).saturating_mul(n as Weight)))
+            .saturating_add(DbWeight::get().writes((14 as Weight).saturating_mul(n as Weight)))
+    }
+    fn transfer_batch(n: u32) -> Weight {
+        (12_000_000 as Weight)
+            .saturating_add((53_000_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add(DbWeight::get().reads((10 as Weight).saturating_mul(n as Weight)))
+            .saturating_add(DbWeight::get().writes((11 as Weight).saturating_mul(n as Weight)))
+    }
+    fn set_attribute() -> Weight {
+        (32_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn clear_attribute() -> Weight {
+        (29_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn mint_with_royalty(_c: u32) -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(8 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn list_for_sale() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn cancel_listing() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn buy(_c: u32) -> Weight {
+        (158_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(14 as Weight))
+            .saturating_add(DbWeight::get().writes(14 as Weight))
+    }
+    fn start_auction() -> Weight {
+        (38_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn bid() -> Weight {
+        (61_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn start_dutch_auction() -> Weight {
+        (39_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn settle_auctions(n: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((190_000_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads((14 as Weight).saturating_mul(n as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((14 as Weight).saturating_mul(n as Weight)))
+    }
+    fn make_offer() -> Weight {
+        (68_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn withdraw_offer() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn accept_offer(_c: u32) -> Weight {
+        (172_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(15 as Weight))
+            .saturating_add(DbWeight::get().writes(15 as Weight))
+    }
+    fn prune_offers(n: u32) -> Weight {
+        (4_000_000 as Weight)
+            .saturating_add((30_000_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+    }
+    fn propose_swap() -> Weight {
+        (27_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn cancel_swap() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn accept_swap(_c: u32) -> Weight {
+        (186_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(19 as Weight))
+            .saturating_add(DbWeight::get().writes(19 as Weight))
+    }
+    fn recover(_c: u32) -> Weight {
+        (52_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().writes(8 as Weight))
+    }
+    fn force_transfer(_c: u32) -> Weight {
+        (53_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(11 as Weight))
+            .saturating_add(DbWeight::get().writes(8 as Weight))
+    }
+    fn force_burn(_c: u32) -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().writes(14 as Weight))
+    }
+    fn pause() -> Weight {
+        (16_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn unpause() -> Weight {
+        (16_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn create_collection() -> Weight {
+        (41_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn force_create_collection() -> Weight {
+        (22_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn destroy_collection() -> Weight {
+        (38_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn mint_into(_c: u32) -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(8 as Weight))
+            .saturating_add(DbWeight::get().writes(11 as Weight))
+    }
+    fn set_team() -> Weight {
+        (20_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn transfer_ownership() -> Weight {
+        (44_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn freeze() -> Weight {
+        (26_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn thaw() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn grant_mint_allowance() -> Weight {
+        (18_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn revoke_mint_allowance() -> Weight {
+        (17_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn mint_with_voucher(_c: u32) -> Weight {
+        (98_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(10 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+}
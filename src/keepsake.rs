@@ -0,0 +1,483 @@
+//! # Unique Assets Implementation: Keepsakes
+//!
+//! This pallet exposes capabilities for managing unique assets, also known as
+//! non-fungible tokens (NFTs).
+//!
+//! - [`keepsake::Trait`](./trait.Trait.html)
+//! - [`Calls`](./enum.Call.html)
+//! - [`Errors`](./enum.Error.html)
+//! - [`Events`](./enum.RawEvent.html)
+//!
+//! ## Overview
+//!
+//! Keepsakes mirror the interface of [commodities](../index.html): assets that
+//! share a common metadata structure may be created and distributed by an asset
+//! admin, and asset owners may burn assets, transfer their ownership or
+//! delegate these capabilities to other accounts. The configuration items,
+//! dispatchable functions, events and errors of this pallet have the same
+//! names and types as their counterparts in the commodities pallet (e.g.
+//! `CommodityAdmin`, `CommodityInfo` and `NonexistentCommodity`), so a runtime
+//! may swap one pallet for the other without changing its configuration of
+//! these items or any code that matches on these events and errors. Keepsakes
+//! do not support the other features of commodities, such as attributes,
+//! sales, auctions, locks or collections, so a runtime that swaps the
+//! commodities pallet for this one must drop the configuration of those
+//! features and one that swaps this pallet for the commodities pallet must add
+//! it.
+//!
+//! This pallet implements the [`UniqueAssets`](../nft/trait.UniqueAssets.html)
+//! trait in a way that is optimized for assets that are expected to be traded
+//! rarely. Ownership is recorded in a double map that is keyed by the owner's
+//! account ID and the asset ID, so an asset may be created, destroyed or
+//! transferred without decoding the list of assets that belong to its owner.
+//! The trade-off is that enumerating the assets of an account requires
+//! iterating over a storage prefix.
+//!
+//! ### Dispatchable Functions
+//!
+//! * [`mint`](./enum.Call.html#variant.mint) - Use the provided keepsake info
+//!   to create a new keepsake for the specified user. May only be called by
+//!   the keepsake admin.
+//!
+//! * [`burn`](./enum.Call.html#variant.burn) - Destroy a keepsake. May only be
+//!   called by keepsake owner or an operator for the owner.
+//!
+//! * [`transfer`](./enum.Call.html#variant.transfer) - Transfer ownership of
+//!   a keepsake to another account. May only be called by current keepsake
+//!   owner or an operator for the owner.
+//!
+//! * [`approve`](./enum.Call.html#variant.approve) - Approve an account to
+//!   transfer a keepsake on behalf of its owner. May only be called by current
+//!   keepsake owner or an operator for the owner.
+//!
+//! * [`clear_approval`](./enum.Call.html#variant.clear_approval) - Remove the
+//!   approval for a keepsake. May only be called by current keepsake owner or
+//!   an operator for the owner.
+//!
+//! * [`transfer_from`](./enum.Call.html#variant.transfer_from) - Transfer
+//!   ownership of a keepsake to another account. May be called by current
+//!   keepsake owner, an operator for the owner or by the account that is
+//!   approved to transfer it.
+//!
+//! * [`set_operator`](./enum.Call.html#variant.set_operator) - Authorize an
+//!   account to manage all of the caller's keepsakes.
+//!
+//! * [`revoke_operator`](./enum.Call.html#variant.revoke_operator) - Revoke an
+//!   operator's authorization to manage the caller's keepsakes.
+
+use codec::FullCodec;
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, dispatch, ensure,
+    traits::{EnsureOrigin, Get},
+    Hashable,
+};
+use frame_system::ensure_signed;
+use sp_runtime::traits::{Hash, Member};
+use sp_std::{cmp::Eq, fmt::Debug, vec::Vec};
+
+use crate::nft::{ApprovableUniqueAssets, UniqueAssets};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub trait Trait<I = DefaultInstance>: frame_system::Trait {
+    /// The dispatch origin that is able to mint new instances of this type of keepsake.
+    type CommodityAdmin: EnsureOrigin<Self::Origin>;
+    /// The data type that is used to describe this type of keepsake.
+    type CommodityInfo: Hashable + Member + Debug + Default + FullCodec;
+    /// The maximum number of this type of keepsake that may exist (minted - burned).
+    type CommodityLimit: Get<u128>;
+    /// The maximum number of this type of keepsake that any single account may own.
+    type UserCommodityLimit: Get<u64>;
+    type Event: From<Event<Self, I>> + Into<<Self as frame_system::Trait>::Event>;
+}
+
+/// The runtime system's hashing algorithm is used to uniquely identify keepsakes.
+pub type KeepsakeId<T> = <T as frame_system::Trait>::Hash;
+
+/// Associates a keepsake with its ID.
+pub type Keepsake<T, I> = (KeepsakeId<T>, <T as Trait<I>>::CommodityInfo);
+
+decl_storage! {
+    trait Store for Module<T: Trait<I>, I: Instance = DefaultInstance> as Keepsake {
+        /// The total number of this type of keepsake that exists (minted - burned).
+        Total get(fn total): u128 = 0;
+        /// The total number of this type of keepsake that has been burned (may overflow).
+        Burned get(fn burned): u128 = 0;
+        /// The total number of this type of keepsake owned by an account.
+        TotalForAccount get(fn total_for_account): map hasher(blake2_128_concat) T::AccountId => u64 = 0;
+        /// A mapping from an account and a keepsake ID to the info that defines the keepsake.
+        KeepsakesForAccount get(fn keepsake_for_account): double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) KeepsakeId<T> => T::CommodityInfo;
+        /// A mapping from a keepsake ID to the account that owns it.
+        AccountForKeepsake get(fn account_for_keepsake): map hasher(identity) KeepsakeId<T> => T::AccountId;
+        /// A mapping from a keepsake ID to the account that is approved to transfer it on behalf of its owner.
+        Approved get(fn approved_for): map hasher(identity) KeepsakeId<T> => Option<T::AccountId>;
+        /// A mapping from an owner and an account to whether that account may manage all of the owner's keepsakes.
+        Operators get(fn is_operator): double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) T::AccountId => bool;
+    }
+
+    add_extra_genesis {
+        config(balances): Vec<(T::AccountId, Vec<T::CommodityInfo>)>;
+        build(|config: &GenesisConfig<T, I>| {
+            for (who, assets) in config.balances.iter() {
+                for asset in assets {
+                    match <Module::<T, I> as UniqueAssets::<T::AccountId>>::mint(who, asset.clone()) {
+                        Ok(_) => {}
+                        Err(err) => { panic!("{:?}", err) },
+                    }
+                }
+            }
+        });
+    }
+}
+
+decl_event!(
+    pub enum Event<T, I = DefaultInstance>
+    where
+        KeepsakeId = <T as frame_system::Trait>::Hash,
+        AccountId = <T as frame_system::Trait>::AccountId,
+    {
+        /// The keepsake has been burned.
+        Burned(KeepsakeId),
+        /// The keepsake has been minted and distributed to the account.
+        Minted(KeepsakeId, AccountId),
+        /// Ownership of the keepsake has been transferred to the account.
+        Transferred(KeepsakeId, AccountId),
+        /// The account has been approved to transfer the keepsake.
+        Approved(KeepsakeId, AccountId),
+        /// The approval for the keepsake has been removed.
+        ApprovalCleared(KeepsakeId),
+        /// The owner (first account) has authorized the operator (second account) to manage its keepsakes.
+        OperatorSet(AccountId, AccountId),
+        /// The owner (first account) has revoked the operator's (second account) authorization.
+        OperatorRevoked(AccountId, AccountId),
+    }
+);
+
+decl_error! {
+    pub enum Error for Module<T: Trait<I>, I: Instance> {
+        // Thrown when there is an attempt to mint a duplicate keepsake.
+        CommodityExists,
+        // Thrown when there is an attempt to burn or transfer a nonexistent keepsake.
+        NonexistentCommodity,
+        // Thrown when someone who is neither the owner of a keepsake nor an operator for its owner
+        // attempts to transfer or burn it.
+        NotCommodityOwner,
+        // Thrown when someone who is not the owner of a keepsake, an operator for its owner or
+        // approved to transfer it attempts to transfer it on behalf of its owner.
+        NotApproved,
+        // Thrown when the keepsake admin attempts to mint a keepsake and the maximum number of this
+        // type of keepsake already exists.
+        TooManyCommodities,
+        // Thrown when an attempt is made to mint or transfer a keepsake to an account that already
+        // owns the maximum number of this type of keepsake.
+        TooManyCommoditiesForAccount,
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait<I>, I: Instance = DefaultInstance> for enum Call where origin: T::Origin {
+        type Error = Error<T, I>;
+        fn deposit_event() = default;
+
+        /// Create a new keepsake from the provided keepsake info and identify the specified
+        /// account as its owner. The ID of the new keepsake will be equal to the hash of the info
+        /// that defines it, as calculated by the runtime system's hashing algorithm.
+        ///
+        /// The dispatch origin for this call must be the keepsake admin.
+        ///
+        /// This function will throw an error if it is called with keepsake info that describes
+        /// an existing (duplicate) keepsake, if the maximum number of this type of keepsake already
+        /// exists or if the specified owner already owns the maximum number of this type of
+        /// keepsake.
+        ///
+        /// - `owner_account`: Receiver of the keepsake.
+        /// - `keepsake_info`: The information that defines the keepsake.
+        #[weight = 10_000]
+        pub fn mint(origin, owner_account: T::AccountId, keepsake_info: T::CommodityInfo) -> dispatch::DispatchResult {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            let keepsake_id = <Self as UniqueAssets<_>>::mint(&owner_account, keepsake_info)?;
+            Self::deposit_event(RawEvent::Minted(keepsake_id, owner_account));
+            Ok(())
+        }
+
+        /// Destroy the specified keepsake.
+        ///
+        /// The dispatch origin for this call must be the keepsake owner or an operator for the
+        /// owner.
+        ///
+        /// - `keepsake_id`: The hash (calculated by the runtime system's hashing algorithm)
+        ///   of the info that defines the keepsake to destroy.
+        #[weight = 10_000]
+        pub fn burn(origin, keepsake_id: KeepsakeId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &keepsake_id), Error::<T, I>::NotCommodityOwner);
+
+            <Self as UniqueAssets<_>>::burn(&keepsake_id)?;
+            Self::deposit_event(RawEvent::Burned(keepsake_id));
+            Ok(())
+        }
+
+        /// Transfer a keepsake to a new owner.
+        ///
+        /// The dispatch origin for this call must be the keepsake owner or an operator for the
+        /// owner.
+        ///
+        /// This function will throw an error if the new owner already owns the maximum
+        /// number of this type of keepsake.
+        ///
+        /// - `dest_account`: Receiver of the keepsake.
+        /// - `keepsake_id`: The hash (calculated by the runtime system's hashing algorithm)
+        ///   of the info that defines the keepsake to transfer.
+        #[weight = 10_000]
+        pub fn transfer(origin, dest_account: T::AccountId, keepsake_id: KeepsakeId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &keepsake_id), Error::<T, I>::NotCommodityOwner);
+
+            <Self as UniqueAssets<_>>::transfer(&dest_account, &keepsake_id)?;
+            Self::deposit_event(RawEvent::Transferred(keepsake_id, dest_account));
+            Ok(())
+        }
+
+        /// Approve an account to transfer a keepsake on behalf of its owner. Any existing
+        /// approval for the keepsake is replaced. Approvals are cleared when the keepsake is
+        /// transferred or burned.
+        ///
+        /// The dispatch origin for this call must be the keepsake owner or an operator for the
+        /// owner.
+        ///
+        /// - `approved_account`: The account that may transfer the keepsake.
+        /// - `keepsake_id`: The hash (calculated by the runtime system's hashing algorithm)
+        ///   of the info that defines the keepsake to approve.
+        #[weight = 10_000]
+        pub fn approve(origin, approved_account: T::AccountId, keepsake_id: KeepsakeId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &keepsake_id), Error::<T, I>::NotCommodityOwner);
+
+            <Self as ApprovableUniqueAssets<_>>::approve(&approved_account, &keepsake_id)?;
+            Self::deposit_event(RawEvent::Approved(keepsake_id, approved_account));
+            Ok(())
+        }
+
+        /// Remove the approval for a keepsake.
+        ///
+        /// The dispatch origin for this call must be the keepsake owner or an operator for the
+        /// owner.
+        ///
+        /// - `keepsake_id`: The hash (calculated by the runtime system's hashing algorithm)
+        ///   of the info that defines the keepsake.
+        #[weight = 10_000]
+        pub fn clear_approval(origin, keepsake_id: KeepsakeId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &keepsake_id), Error::<T, I>::NotCommodityOwner);
+
+            <Self as ApprovableUniqueAssets<_>>::clear_approval(&keepsake_id)?;
+            Self::deposit_event(RawEvent::ApprovalCleared(keepsake_id));
+            Ok(())
+        }
+
+        /// Transfer a keepsake to a new owner on behalf of its current owner.
+        ///
+        /// The dispatch origin for this call must be the keepsake owner, an operator for the owner
+        /// or the account that is approved to transfer the keepsake.
+        ///
+        /// This function will throw an error if the new owner already owns the maximum
+        /// number of this type of keepsake.
+        ///
+        /// - `dest_account`: Receiver of the keepsake.
+        /// - `keepsake_id`: The hash (calculated by the runtime system's hashing algorithm)
+        ///   of the info that defines the keepsake to transfer.
+        #[weight = 10_000]
+        pub fn transfer_from(origin, dest_account: T::AccountId, keepsake_id: KeepsakeId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::is_owner_or_operator(&who, &keepsake_id)
+                    || Some(&who) == Self::approved_for(keepsake_id).as_ref(),
+                Error::<T, I>::NotApproved
+            );
+
+            <Self as UniqueAssets<_>>::transfer(&dest_account, &keepsake_id)?;
+            Self::deposit_event(RawEvent::Transferred(keepsake_id, dest_account));
+            Ok(())
+        }
+
+        /// Authorize an account to manage all of the caller's keepsakes of this type. An
+        /// operator may burn, transfer and approve any keepsake owned by the caller until its
+        /// authorization is revoked.
+        ///
+        /// - `operator_account`: The account to authorize.
+        #[weight = 10_000]
+        pub fn set_operator(origin, operator_account: T::AccountId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            <Self as ApprovableUniqueAssets<_>>::set_operator(&who, &operator_account);
+            Self::deposit_event(RawEvent::OperatorSet(who, operator_account));
+            Ok(())
+        }
+
+        /// Revoke an account's authorization to manage the caller's keepsakes of this type.
+        ///
+        /// - `operator_account`: The account whose authorization to revoke.
+        #[weight = 10_000]
+        pub fn revoke_operator(origin, operator_account: T::AccountId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            <Self as ApprovableUniqueAssets<_>>::revoke_operator(&who, &operator_account);
+            Self::deposit_event(RawEvent::OperatorRevoked(who, operator_account));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// Whether an account is the owner of a keepsake or an operator for its owner.
+    fn is_owner_or_operator(who: &T::AccountId, keepsake_id: &KeepsakeId<T>) -> bool {
+        let owner = Self::account_for_keepsake(keepsake_id);
+        *who == owner || Self::is_operator(&owner, who)
+    }
+}
+
+impl<T: Trait<I>, I: Instance> UniqueAssets<T::AccountId> for Module<T, I> {
+    type AssetId = KeepsakeId<T>;
+    type AssetInfo = T::CommodityInfo;
+    type AssetLimit = T::CommodityLimit;
+    type UserAssetLimit = T::UserCommodityLimit;
+
+    fn total() -> u128 {
+        Self::total()
+    }
+
+    fn burned() -> u128 {
+        Self::burned()
+    }
+
+    fn total_for_account(account: &T::AccountId) -> u64 {
+        Self::total_for_account(account)
+    }
+
+    fn assets_for_account(account: &T::AccountId) -> Vec<Keepsake<T, I>> {
+        KeepsakesForAccount::<T, I>::iter_prefix(account).collect()
+    }
+
+    fn owner_of(keepsake_id: &KeepsakeId<T>) -> T::AccountId {
+        Self::account_for_keepsake(keepsake_id)
+    }
+
+    fn mint(
+        owner_account: &T::AccountId,
+        keepsake_info: <T as Trait<I>>::CommodityInfo,
+    ) -> dispatch::result::Result<KeepsakeId<T>, dispatch::DispatchError> {
+        let keepsake_id = T::Hashing::hash_of(&keepsake_info);
+
+        ensure!(
+            !AccountForKeepsake::<T, I>::contains_key(keepsake_id),
+            Error::<T, I>::CommodityExists
+        );
+
+        ensure!(
+            Self::total_for_account(owner_account) < T::UserCommodityLimit::get(),
+            Error::<T, I>::TooManyCommoditiesForAccount
+        );
+
+        ensure!(
+            Self::total() < T::CommodityLimit::get(),
+            Error::<T, I>::TooManyCommodities
+        );
+
+        Total::<I>::mutate(|total| *total += 1);
+        TotalForAccount::<T, I>::mutate(owner_account, |total| *total += 1);
+        KeepsakesForAccount::<T, I>::insert(owner_account, keepsake_id, keepsake_info);
+        AccountForKeepsake::<T, I>::insert(keepsake_id, owner_account);
+
+        Ok(keepsake_id)
+    }
+
+    fn burn(keepsake_id: &KeepsakeId<T>) -> dispatch::DispatchResult {
+        let owner = Self::owner_of(keepsake_id);
+        ensure!(
+            owner != T::AccountId::default(),
+            Error::<T, I>::NonexistentCommodity
+        );
+
+        Total::<I>::mutate(|total| *total -= 1);
+        Burned::<I>::mutate(|total| *total += 1);
+        TotalForAccount::<T, I>::mutate(&owner, |total| *total -= 1);
+        KeepsakesForAccount::<T, I>::remove(&owner, keepsake_id);
+        AccountForKeepsake::<T, I>::remove(keepsake_id);
+        Approved::<T, I>::remove(keepsake_id);
+
+        Ok(())
+    }
+
+    fn transfer(
+        dest_account: &T::AccountId,
+        keepsake_id: &KeepsakeId<T>,
+    ) -> dispatch::DispatchResult {
+        let owner = Self::owner_of(keepsake_id);
+        ensure!(
+            owner != T::AccountId::default(),
+            Error::<T, I>::NonexistentCommodity
+        );
+
+        ensure!(
+            Self::total_for_account(dest_account) < T::UserCommodityLimit::get(),
+            Error::<T, I>::TooManyCommoditiesForAccount
+        );
+
+        TotalForAccount::<T, I>::mutate(&owner, |total| *total -= 1);
+        TotalForAccount::<T, I>::mutate(dest_account, |total| *total += 1);
+        let keepsake_info = KeepsakesForAccount::<T, I>::take(&owner, keepsake_id);
+        KeepsakesForAccount::<T, I>::insert(dest_account, keepsake_id, keepsake_info);
+        AccountForKeepsake::<T, I>::insert(keepsake_id, dest_account);
+        Approved::<T, I>::remove(keepsake_id);
+
+        Ok(())
+    }
+}
+
+impl<T: Trait<I>, I: Instance> ApprovableUniqueAssets<T::AccountId> for Module<T, I> {
+    fn approved_for(keepsake_id: &KeepsakeId<T>) -> Option<T::AccountId> {
+        Self::approved_for(keepsake_id)
+    }
+
+    fn approve(
+        approved_account: &T::AccountId,
+        keepsake_id: &KeepsakeId<T>,
+    ) -> dispatch::DispatchResult {
+        ensure!(
+            AccountForKeepsake::<T, I>::contains_key(keepsake_id),
+            Error::<T, I>::NonexistentCommodity
+        );
+
+        Approved::<T, I>::insert(keepsake_id, approved_account);
+
+        Ok(())
+    }
+
+    fn clear_approval(keepsake_id: &KeepsakeId<T>) -> dispatch::DispatchResult {
+        ensure!(
+            AccountForKeepsake::<T, I>::contains_key(keepsake_id),
+            Error::<T, I>::NonexistentCommodity
+        );
+
+        Approved::<T, I>::remove(keepsake_id);
+
+        Ok(())
+    }
+
+    fn is_operator(owner_account: &T::AccountId, operator_account: &T::AccountId) -> bool {
+        Self::is_operator(owner_account, operator_account)
+    }
+
+    fn set_operator(owner_account: &T::AccountId, operator_account: &T::AccountId) {
+        Operators::<T, I>::insert(owner_account, operator_account, true);
+    }
+
+    fn revoke_operator(owner_account: &T::AccountId, operator_account: &T::AccountId) {
+        Operators::<T, I>::remove(owner_account, operator_account);
+    }
+}
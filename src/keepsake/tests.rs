@@ -0,0 +1,448 @@
+// Tests for the keepsake pallet
+
+use super::mock::*;
+use crate::keepsake::*;
+use crate::nft::{ApprovableUniqueAssets, UniqueAssets};
+use frame_support::{assert_err, assert_ok, Hashable};
+use sp_core::H256;
+
+#[test]
+fn mint() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(SUT::total(), 0);
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(<SUT as UniqueAssets<_>>::total(), 0);
+        assert_eq!(<SUT as UniqueAssets<_>>::total_for_account(&1), 0);
+        assert_eq!(
+            SUT::account_for_keepsake::<H256>(Vec::<u8>::default().blake2_256().into()),
+            0
+        );
+
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_eq!(SUT::total(), 1);
+        assert_eq!(<SUT as UniqueAssets<_>>::total(), 1);
+        assert_eq!(SUT::burned(), 0);
+        assert_eq!(<SUT as UniqueAssets<_>>::burned(), 0);
+        assert_eq!(SUT::total_for_account(1), 1);
+        assert_eq!(<SUT as UniqueAssets<_>>::total_for_account(&1), 1);
+        let keepsakes_for_account = <SUT as UniqueAssets<_>>::assets_for_account(&1);
+        assert_eq!(keepsakes_for_account.len(), 1);
+        assert_eq!(
+            keepsakes_for_account[0].0,
+            Vec::<u8>::default().blake2_256().into()
+        );
+        assert_eq!(keepsakes_for_account[0].1, Vec::<u8>::default());
+        assert_eq!(
+            SUT::account_for_keepsake::<H256>(Vec::<u8>::default().blake2_256().into()),
+            1
+        );
+    });
+}
+
+#[test]
+fn mint_err_non_admin() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::mint(Origin::signed(1), 1, Vec::<u8>::default()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn mint_err_dupe() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::mint(Origin::root(), 2, Vec::<u8>::default()),
+            Error::<Test, DefaultInstance>::CommodityExists
+        );
+    });
+}
+
+#[test]
+fn mint_err_max_user() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![]));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![0]));
+
+        assert_err!(
+            SUT::mint(Origin::root(), 1, vec![1]),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+    });
+}
+
+#[test]
+fn mint_err_max() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![]));
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![0]));
+        assert_ok!(SUT::mint(Origin::root(), 3, vec![1]));
+        assert_ok!(SUT::mint(Origin::root(), 4, vec![2]));
+        assert_ok!(SUT::mint(Origin::root(), 5, vec![3]));
+
+        assert_err!(
+            SUT::mint(Origin::root(), 6, vec![4]),
+            Error::<Test, DefaultInstance>::TooManyCommodities
+        );
+    });
+}
+
+#[test]
+fn burn() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::burn(
+            Origin::signed(1),
+            Vec::<u8>::default().blake2_256().into()
+        ));
+
+        assert_eq!(SUT::total(), 0);
+        assert_eq!(SUT::burned(), 1);
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(<SUT as UniqueAssets<_>>::assets_for_account(&1), vec![]);
+        assert_eq!(
+            SUT::account_for_keepsake::<H256>(Vec::<u8>::default().blake2_256().into()),
+            0
+        );
+    });
+}
+
+#[test]
+fn burn_err_not_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::burn(Origin::signed(2), Vec::<u8>::default().blake2_256().into()),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn burn_err_not_exist() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::burn(Origin::signed(1), Vec::<u8>::default().blake2_256().into()),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn transfer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            2,
+            Vec::<u8>::default().blake2_256().into()
+        ));
+
+        assert_eq!(SUT::total(), 1);
+        assert_eq!(SUT::burned(), 0);
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(SUT::total_for_account(2), 1);
+        assert_eq!(<SUT as UniqueAssets<_>>::assets_for_account(&1), vec![]);
+        let keepsakes_for_account = <SUT as UniqueAssets<_>>::assets_for_account(&2);
+        assert_eq!(keepsakes_for_account.len(), 1);
+        assert_eq!(
+            keepsakes_for_account[0].0,
+            Vec::<u8>::default().blake2_256().into()
+        );
+        assert_eq!(keepsakes_for_account[0].1, Vec::<u8>::default());
+        assert_eq!(
+            SUT::account_for_keepsake::<H256>(Vec::<u8>::default().blake2_256().into()),
+            2
+        );
+    });
+}
+
+#[test]
+fn transfer_err_not_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(0),
+                2,
+                Vec::<u8>::default().blake2_256().into()
+            ),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn transfer_err_not_exist() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(1),
+                2,
+                Vec::<u8>::default().blake2_256().into()
+            ),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn transfer_err_max_user() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![0]));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![1]));
+        assert_ok!(SUT::mint(Origin::root(), 2, Vec::<u8>::default()));
+        assert_eq!(
+            SUT::account_for_keepsake::<H256>(Vec::<u8>::default().blake2_256().into()),
+            2
+        );
+
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(2),
+                1,
+                Vec::<u8>::default().blake2_256().into()
+            ),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+    });
+}
+
+#[test]
+fn approve() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            Vec::<u8>::default().blake2_256().into()
+        ));
+
+        assert_eq!(
+            SUT::approved_for::<H256>(Vec::<u8>::default().blake2_256().into()),
+            Some(2)
+        );
+        assert_eq!(
+            <SUT as ApprovableUniqueAssets<_>>::approved_for(
+                &Vec::<u8>::default().blake2_256().into()
+            ),
+            Some(2)
+        );
+    });
+}
+
+#[test]
+fn approve_err_not_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::approve(
+                Origin::signed(2),
+                2,
+                Vec::<u8>::default().blake2_256().into()
+            ),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn clear_approval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            Vec::<u8>::default().blake2_256().into()
+        ));
+        assert_ok!(SUT::clear_approval(
+            Origin::signed(1),
+            Vec::<u8>::default().blake2_256().into()
+        ));
+
+        assert_eq!(
+            SUT::approved_for::<H256>(Vec::<u8>::default().blake2_256().into()),
+            None
+        );
+    });
+}
+
+#[test]
+fn transfer_from() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            Vec::<u8>::default().blake2_256().into()
+        ));
+        assert_ok!(SUT::transfer_from(
+            Origin::signed(2),
+            3,
+            Vec::<u8>::default().blake2_256().into()
+        ));
+
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(SUT::total_for_account(3), 1);
+        assert_eq!(
+            SUT::account_for_keepsake::<H256>(Vec::<u8>::default().blake2_256().into()),
+            3
+        );
+        assert_eq!(
+            SUT::approved_for::<H256>(Vec::<u8>::default().blake2_256().into()),
+            None
+        );
+    });
+}
+
+#[test]
+fn transfer_from_err_not_approved() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            Vec::<u8>::default().blake2_256().into()
+        ));
+
+        assert_err!(
+            SUT::transfer_from(
+                Origin::signed(3),
+                3,
+                Vec::<u8>::default().blake2_256().into()
+            ),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn transfer_clears_approval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            Vec::<u8>::default().blake2_256().into()
+        ));
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            3,
+            Vec::<u8>::default().blake2_256().into()
+        ));
+
+        assert_eq!(
+            SUT::approved_for::<H256>(Vec::<u8>::default().blake2_256().into()),
+            None
+        );
+        assert_err!(
+            SUT::transfer_from(
+                Origin::signed(2),
+                2,
+                Vec::<u8>::default().blake2_256().into()
+            ),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn burn_clears_approval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            Vec::<u8>::default().blake2_256().into()
+        ));
+        assert_ok!(SUT::burn(
+            Origin::signed(1),
+            Vec::<u8>::default().blake2_256().into()
+        ));
+
+        assert_eq!(
+            SUT::approved_for::<H256>(Vec::<u8>::default().blake2_256().into()),
+            None
+        );
+    });
+}
+
+#[test]
+fn set_operator() {
+    new_test_ext().execute_with(|| {
+        assert!(!SUT::is_operator(1, 2));
+        assert_ok!(SUT::set_operator(Origin::signed(1), 2));
+
+        assert!(SUT::is_operator(1, 2));
+        assert!(<SUT as ApprovableUniqueAssets<_>>::is_operator(&1, &2));
+        assert!(!SUT::is_operator(2, 1));
+    });
+}
+
+#[test]
+fn revoke_operator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::set_operator(Origin::signed(1), 2));
+        assert_ok!(SUT::revoke_operator(Origin::signed(1), 2));
+
+        assert!(!SUT::is_operator(1, 2));
+    });
+}
+
+#[test]
+fn transfer_by_operator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::set_operator(Origin::signed(1), 2));
+        assert_ok!(SUT::transfer(
+            Origin::signed(2),
+            3,
+            Vec::<u8>::default().blake2_256().into()
+        ));
+
+        assert_eq!(
+            SUT::account_for_keepsake::<H256>(Vec::<u8>::default().blake2_256().into()),
+            3
+        );
+    });
+}
+
+#[test]
+fn burn_by_operator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::set_operator(Origin::signed(1), 2));
+        assert_ok!(SUT::burn(
+            Origin::signed(2),
+            Vec::<u8>::default().blake2_256().into()
+        ));
+
+        assert_eq!(SUT::total(), 0);
+        assert_eq!(SUT::total_for_account(1), 0);
+    });
+}
+
+#[test]
+fn transfer_err_revoked_operator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::set_operator(Origin::signed(1), 2));
+        assert_ok!(SUT::revoke_operator(Origin::signed(1), 2));
+
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(2),
+                3,
+                Vec::<u8>::default().blake2_256().into()
+            ),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
@@ -14,40 +14,344 @@
 //! by an asset admin. Asset owners may burn assets or transfer their
 //! ownership. Configuration parameters are used to limit the total number of a
 //! type of asset that may exist as well as the number that any one account may
-//! own. Assets are uniquely identified by the hash of the info that defines
-//! them, as calculated by the runtime system's hashing algorithm.
+//! own. Assets are uniquely identified by an ID that is generated by the
+//! configured [`AssetIdGenerator`](./trait.Trait.html#associatedtype.AssetIdGenerator).
+//! The [`HashOfInfo`](./struct.HashOfInfo.html) generator identifies assets by
+//! the hash of the info that defines them, as calculated by the runtime
+//! system's hashing algorithm, which prevents duplicate assets within a
+//! collection; assets in other collections than the default collection are
+//! identified by the hash of their collection ID and info, and the hashed data
+//! is tagged so that IDs in different collections may not collide. The
+//! [`Sequential`](./struct.Sequential.html) generator identifies assets by the
+//! order in which they were minted, which allows multiple assets to share the
+//! same info.
 //!
 //! This pallet implements the [`UniqueAssets`](./nft/trait.UniqueAssets.html)
 //! trait in a way that is optimized for assets that are expected to be traded
-//! frequently.
+//! frequently. The info that defines a commodity is stored once, keyed by the
+//! commodity's ID, and ownership is recorded in a double map that is keyed by
+//! the owner's account ID and the commodity ID, so transferring a commodity
+//! only updates the ownership index.
+//!
+//! Before a commodity is minted for or transferred to an account, the
+//! configured [`OnCommodityReceived`](./trait.OnCommodityReceived.html) hook is
+//! consulted, which allows runtimes to prevent commodities from being sent to
+//! accounts that are unable to handle them.
+//!
+//! Every commodity occupies storage, so a deposit that is proportional to the
+//! encoded size of its info is reserved when it is minted. The deposit is
+//! reserved from the account that signs the call that mints the commodity or,
+//! if the call is not signed (e.g. by root), from its owner. The deposit moves
+//! with the commodity when it is transferred, which requires the receiving
+//! account to exist, and is released to the account that holds it when the
+//! commodity is burned. The deposit is configured by way of the
+//! [`DepositBase`](./trait.Trait.html#associatedtype.DepositBase) and
+//! [`DepositPerByte`](./trait.Trait.html#associatedtype.DepositPerByte)
+//! parameters.
+//!
+//! Each commodity may also have a set of mutable attributes, stored as
+//! key/value pairs, that describe properties of the commodity that change over
+//! its lifetime. The configured
+//! [`AttributePolicy`](./enum.AttributePolicy.html) determines whether
+//! attributes are managed by the commodity owner, the commodity admin or both.
+//! The number of attributes that a commodity may have is limited by the
+//! configured [`MaxAttributes`](./trait.Trait.html#associatedtype.MaxAttributes),
+//! and a commodity's attributes are removed when it is burned.
+//!
+//! A commodity may be minted with a royalty, which entitles a recipient
+//! account to a fixed portion of the price whenever the commodity is sold.
+//! Sales are paid in the configured
+//! [`Currency`](./trait.Trait.html#associatedtype.Currency) and every sale path
+//! pays out royalties by way of
+//! [`pay_for_commodity`](./struct.Module.html#method.pay_for_commodity).
+//!
+//! Commodity owners may list their commodities for sale at a fixed price. A
+//! listed commodity may be bought by any account that is willing to pay the
+//! listing price, in which case payment and transfer of ownership happen
+//! atomically. A listing is removed when its commodity is transferred or
+//! burned.
+//!
+//! Commodity owners may also sell their commodities in English (ascending
+//! price) or Dutch (descending price) [`auctions`](./auctions/index.html). A
+//! commodity may not be transferred, burned or listed for sale by any means,
+//! including forced transfers and burns, while it is being auctioned.
+//!
+//! Any account may make an [`offer`](./offers/index.html) to buy a commodity,
+//! whether or not it is listed for sale. The amount of the offer is reserved
+//! until the offer is withdrawn, accepted by the commodity owner or expires.
+//!
+//! Commodity owners may propose to [`swap`](./swaps/index.html) a commodity,
+//! optionally along with an amount of currency, for a unique asset from the
+//! configured [`SwapAssets`](./trait.Trait.html#associatedtype.SwapAssets),
+//! which may belong to another pallet instance. When the owner of that asset
+//! accepts the proposal, both assets change hands or neither does.
+//!
+//! Other pallets may lock a commodity in place, for instance while it is used
+//! as collateral, by way of the
+//! [`LockableUniqueAssets`](./nft/trait.LockableUniqueAssets.html) trait. A
+//! commodity may not be transferred or burned while any of its named locks is
+//! held.
+//!
+//! A type of commodity may be configured to be non-transferable (soulbound),
+//! e.g. for credentials and badges, by way of the
+//! [`Transferable`](./trait.Trait.html#associatedtype.Transferable) parameter.
+//! Non-transferable commodities may be minted and burned, but may only change
+//! hands when the commodity admin recovers them.
+//!
+//! The configured [`ForceOrigin`](./trait.Trait.html#associatedtype.ForceOrigin)
+//! may transfer or burn any commodity that is not locked without the consent of
+//! its owner, e.g. to enforce a court order. It may also pause minting,
+//! burning or transferring commodities, or all three, during an incident.
+//!
+//! Commodities are grouped into [`collections`](./collections/index.html), so
+//! that many types of commodity may share a single pallet instance. Every
+//! commodity belongs to the default collection, which is managed by the
+//! commodity admin and limited by the configured limits, unless it is minted
+//! into a collection with its own limits and metadata. Any account may create
+//! a collection by reserving the configured
+//! [`CollectionDeposit`](./trait.Trait.html#associatedtype.CollectionDeposit),
+//! which is released when the collection is destroyed. Each collection is
+//! managed by a [`Team`](./collections/struct.Team.html) of accounts with
+//! distinct roles: the issuer mints commodities, the admin transfers and burns
+//! them without the consent of their owners and the freezer freezes and thaws
+//! them. This pallet
+//! implements the [`UniqueCollections`](./nft/trait.UniqueCollections.html)
+//! trait.
+//!
+//! The issuer of a collection may sign [`vouchers`](./vouchers/index.html)
+//! off-chain that entitle their recipients to commodities in the collection,
+//! which are only minted when the vouchers are redeemed (lazy minting). A
+//! voucher may be redeemed once, before it expires, by way of a signed or an
+//! unsigned transaction.
+//!
+//! The [`keepsake`](./keepsake/index.html) module provides an alternative
+//! implementation that is optimized for assets that are expected to be traded
+//! rarely.
 //!
 //! ### Dispatchable Functions
 //!
 //! * [`mint`](./enum.Call.html#variant.mint) - Use the provided commodity info
 //!   to create a new commodity for the specified user. May only be called by
-//!   the commodity admin.
+//!   the commodity admin, the issuer of the default collection or an account
+//!   with a remaining mint allowance.
 //!
 //! * [`burn`](./enum.Call.html#variant.burn) - Destroy a commodity. May only be
-//!   called by commodity owner.
+//!   called by commodity owner or an operator for the owner.
 //!
 //! * [`transfer`](./enum.Call.html#variant.transfer) - Transfer ownership of
 //!   a commodity to another account. May only be called by current commodity
-//!   owner.
+//!   owner or an operator for the owner.
+//!
+//! * [`approve`](./enum.Call.html#variant.approve) - Approve an account to
+//!   transfer a commodity on behalf of its owner. May only be called by current
+//!   commodity owner or an operator for the owner.
+//!
+//! * [`clear_approval`](./enum.Call.html#variant.clear_approval) - Remove the
+//!   approval for a commodity. May only be called by current commodity owner or
+//!   an operator for the owner.
+//!
+//! * [`transfer_from`](./enum.Call.html#variant.transfer_from) - Transfer
+//!   ownership of a commodity to another account. May be called by current
+//!   commodity owner, an operator for the owner or by the account that is
+//!   approved to transfer it.
+//!
+//! * [`safe_transfer`](./enum.Call.html#variant.safe_transfer) - Transfer
+//!   ownership of a commodity to another account, passing data to the
+//!   receiver hook. May be called by current commodity owner, an operator for
+//!   the owner or by the account that is approved to transfer it.
+//!
+//! * [`set_operator`](./enum.Call.html#variant.set_operator) - Authorize an
+//!   account to manage all of the caller's commodities.
+//!
+//! * [`revoke_operator`](./enum.Call.html#variant.revoke_operator) - Revoke an
+//!   operator's authorization to manage the caller's commodities.
+//!
+//! * [`mint_batch`](./enum.Call.html#variant.mint_batch) - Create a batch of
+//!   commodities, each for its specified user. Either all of the commodities
+//!   are created or none are. May only be called by the commodity admin or the
+//!   issuer of the default collection.
+//!
+//! * [`burn_batch`](./enum.Call.html#variant.burn_batch) - Destroy a batch of
+//!   commodities. Either all of the commodities are destroyed or none are. May
+//!   only be called by the owner of each commodity or an operator for the owner.
+//!
+//! * [`transfer_batch`](./enum.Call.html#variant.transfer_batch) - Transfer
+//!   ownership of a batch of commodities to another account. Either all of the
+//!   commodities are transferred or none are. May only be called by the owner
+//!   of each commodity or an operator for the owner.
+//!
+//! * [`set_attribute`](./enum.Call.html#variant.set_attribute) - Set the value
+//!   of a commodity attribute. May only be called by the origins that are
+//!   allowed by the attribute policy.
+//!
+//! * [`clear_attribute`](./enum.Call.html#variant.clear_attribute) - Remove a
+//!   commodity attribute. May only be called by the origins that are allowed by
+//!   the attribute policy.
+//!
+//! * [`mint_with_royalty`](./enum.Call.html#variant.mint_with_royalty) - Use
+//!   the provided commodity info to create a new commodity for the specified
+//!   user that entitles a recipient to a royalty when it is sold. May only be
+//!   called by the commodity admin or the issuer of the default collection.
+//!
+//! * [`list_for_sale`](./enum.Call.html#variant.list_for_sale) - List a
+//!   commodity for sale at a fixed price. May only be called by current
+//!   commodity owner or an operator for the owner.
+//!
+//! * [`cancel_listing`](./enum.Call.html#variant.cancel_listing) - Remove the
+//!   listing for a commodity. May only be called by current commodity owner or
+//!   an operator for the owner.
+//!
+//! * [`buy`](./enum.Call.html#variant.buy) - Pay the listing price or the
+//!   current Dutch auction price for a commodity and take ownership of it.
+//!
+//! * [`start_auction`](./enum.Call.html#variant.start_auction) - Auction a
+//!   commodity with a reserve price and an end block. May only be called by
+//!   current commodity owner or an operator for the owner.
+//!
+//! * [`bid`](./enum.Call.html#variant.bid) - Bid on an auction, reserving the
+//!   amount of the bid until the caller is outbid or the auction ends.
+//!
+//! * [`start_dutch_auction`](./enum.Call.html#variant.start_dutch_auction) -
+//!   Sell a commodity at a price that decays with each block from a start price
+//!   to a floor price. May only be called by current commodity owner or an
+//!   operator for the owner.
+//!
+//! * [`make_offer`](./enum.Call.html#variant.make_offer) - Offer to buy a
+//!   commodity, reserving the amount of the offer until it is withdrawn,
+//!   accepted or expires.
+//!
+//! * [`withdraw_offer`](./enum.Call.html#variant.withdraw_offer) - Withdraw an
+//!   offer to buy a commodity, releasing its amount.
+//!
+//! * [`accept_offer`](./enum.Call.html#variant.accept_offer) - Sell a
+//!   commodity to the account that made an offer for it. May only be called by
+//!   current commodity owner or an operator for the owner.
+//!
+//! * [`propose_swap`](./enum.Call.html#variant.propose_swap) - Propose to swap
+//!   a commodity, and optionally an amount of currency, for another asset. May
+//!   only be called by current commodity owner or an operator for the owner;
+//!   only the owner may add currency.
+//!
+//! * [`cancel_swap`](./enum.Call.html#variant.cancel_swap) - Remove the swap
+//!   proposal for a commodity. May only be called by current commodity owner or
+//!   an operator for the owner.
+//!
+//! * [`accept_swap`](./enum.Call.html#variant.accept_swap) - Exchange the
+//!   caller's asset for the commodity and currency of a swap proposal. May only
+//!   be called by the owner of the wanted asset.
+//!
+//! * [`recover`](./enum.Call.html#variant.recover) - Transfer a commodity to a
+//!   new owner on behalf of its current owner, even if the commodity is not
+//!   transferable. May only be called by the commodity admin.
+//!
+//! * [`force_transfer`](./enum.Call.html#variant.force_transfer) - Transfer a
+//!   commodity from its expected owner to another account without the owner's
+//!   consent. May only be called by the force origin or the admin of the
+//!   commodity's collection.
+//!
+//! * [`force_burn`](./enum.Call.html#variant.force_burn) - Destroy a commodity
+//!   without its owner's consent. May only be called by the force origin or
+//!   the admin of the commodity's collection.
+//!
+//! * [`pause`](./enum.Call.html#variant.pause) - Halt minting, burning or
+//!   transferring commodities, or all three. May only be called by the force
+//!   origin.
+//!
+//! * [`unpause`](./enum.Call.html#variant.unpause) - Resume an operation that
+//!   has been paused. May only be called by the force origin.
+//!
+//! * [`create_collection`](./enum.Call.html#variant.create_collection) - Create
+//!   a collection with its own limits and metadata that is owned and managed by
+//!   the caller, reserving the collection deposit.
+//!
+//! * [`force_create_collection`](./enum.Call.html#variant.force_create_collection) -
+//!   Create a collection with its own owner, limits and metadata without a
+//!   deposit. May only be called by the commodity admin.
+//!
+//! * [`destroy_collection`](./enum.Call.html#variant.destroy_collection) -
+//!   Destroy a collection that has no commodities, releasing its deposit. May
+//!   only be called by the owner of the collection.
+//!
+//! * [`mint_into`](./enum.Call.html#variant.mint_into) - Use the provided
+//!   commodity info to create a new commodity in a collection for the specified
+//!   user. May only be called by the issuer of the collection.
+//!
+//! * [`set_team`](./enum.Call.html#variant.set_team) - Appoint the issuer,
+//!   admin and freezer of a collection. May only be called by the owner of the
+//!   collection, or the commodity admin in the case of the default collection.
+//!
+//! * [`transfer_ownership`](./enum.Call.html#variant.transfer_ownership) -
+//!   Transfer ownership of a collection, and its deposit, to another account.
+//!   May only be called by the owner of the collection.
+//!
+//! * [`freeze`](./enum.Call.html#variant.freeze) - Lock a commodity so that it
+//!   may not be transferred or burned. May only be called by the force origin
+//!   or the freezer of the commodity's collection.
+//!
+//! * [`thaw`](./enum.Call.html#variant.thaw) - Release the lock that was placed
+//!   on a commodity by `freeze`. May only be called by the force origin or the
+//!   freezer of the commodity's collection.
+//!
+//! * [`grant_mint_allowance`](./enum.Call.html#variant.grant_mint_allowance) -
+//!   Allow an account to mint a number of commodities in the default
+//!   collection. May only be called by the commodity admin.
+//!
+//! * [`revoke_mint_allowance`](./enum.Call.html#variant.revoke_mint_allowance) -
+//!   Remove the mint allowance of an account. May only be called by the
+//!   commodity admin.
+//!
+//! * [`mint_with_voucher`](./enum.Call.html#variant.mint_with_voucher) - Create
+//!   the commodity of a voucher that has been signed by the issuer of its
+//!   collection. May be called by any account or submitted as an unsigned
+//!   transaction.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![recursion_limit = "256"]
 
-use codec::FullCodec;
+use codec::{Decode, Encode, FullCodec};
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, dispatch, ensure,
-    traits::{EnsureOrigin, Get},
-    Hashable,
+    decl_error, decl_event, decl_module, decl_storage,
+    dispatch::{self, DispatchResult},
+    ensure,
+    traits::{
+        BalanceStatus, Currency, EnsureOrigin, ExistenceRequirement, Get, LockIdentifier,
+        ReservableCurrency,
+    },
+    transactional,
+    weights::Weight,
+    Hashable, Parameter,
 };
-use frame_system::ensure_signed;
-use sp_runtime::traits::{Hash, Member};
-use sp_std::{cmp::Eq, fmt::Debug, vec::Vec};
+use frame_system::{ensure_none, ensure_signed};
+use sp_runtime::{
+    traits::{
+        AtLeast32BitUnsigned, Hash, IdentifyAccount, Member, SaturatedConversion, Saturating,
+        Verify, Zero,
+    },
+    transaction_validity::TransactionPriority,
+    Perbill, RuntimeDebug,
+};
+use sp_std::{cmp::Eq, fmt::Debug, marker::PhantomData, vec::Vec};
 
+pub mod auctions;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod collections;
+mod default_weights;
+pub mod keepsake;
+mod migration;
 pub mod nft;
-pub use crate::nft::UniqueAssets;
+pub mod offers;
+pub mod swaps;
+pub mod vouchers;
+pub use crate::auctions::{Auction, AuctionOf, DutchAuction, DutchAuctionOf};
+pub use crate::collections::{Collection, CollectionOf, Role, Team, FREEZE_LOCK};
+pub use crate::nft::{
+    ApprovableUniqueAssets, GenerateAssetId, LockableUniqueAssets, RoyaltyBearingUniqueAssets,
+    UniqueAssets, UniqueCollections,
+};
+pub use crate::offers::{Offer, OfferOf};
+pub use crate::swaps::{Swap, SwapOf};
+pub use crate::vouchers::{MintVoucher, MintVoucherOf};
 
 #[cfg(test)]
 mod mock;
@@ -55,24 +359,238 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+/// Weight functions for the dispatchable functions of this pallet. Functions that are
+/// parameterized by `c` receive the number of commodities owned by the account that owns (or will
+/// own) the commodity. The implementation for `()` provides placeholder weights that have not been
+/// generated by the benchmarks.
+pub trait WeightInfo {
+    fn mint(c: u32) -> Weight;
+    fn burn(c: u32) -> Weight;
+    fn transfer(c: u32) -> Weight;
+    fn approve() -> Weight;
+    fn clear_approval() -> Weight;
+    fn transfer_from(c: u32) -> Weight;
+    fn safe_transfer(c: u32) -> Weight;
+    fn set_operator() -> Weight;
+    fn revoke_operator() -> Weight;
+    fn mint_batch(n: u32) -> Weight;
+    fn burn_batch(n: u32) -> Weight;
+    fn transfer_batch(n: u32) -> Weight;
+    fn set_attribute() -> Weight;
+    fn clear_attribute() -> Weight;
+    fn mint_with_royalty(c: u32) -> Weight;
+    fn list_for_sale() -> Weight;
+    fn cancel_listing() -> Weight;
+    fn buy(c: u32) -> Weight;
+    fn start_auction() -> Weight;
+    fn bid() -> Weight;
+    fn settle_auctions(n: u32) -> Weight;
+    fn start_dutch_auction() -> Weight;
+    fn make_offer() -> Weight;
+    fn withdraw_offer() -> Weight;
+    fn accept_offer(c: u32) -> Weight;
+    fn prune_offers(n: u32) -> Weight;
+    fn propose_swap() -> Weight;
+    fn cancel_swap() -> Weight;
+    fn accept_swap(c: u32) -> Weight;
+    fn recover(c: u32) -> Weight;
+    fn force_transfer(c: u32) -> Weight;
+    fn force_burn(c: u32) -> Weight;
+    fn pause() -> Weight;
+    fn unpause() -> Weight;
+    fn create_collection() -> Weight;
+    fn force_create_collection() -> Weight;
+    fn destroy_collection() -> Weight;
+    fn mint_into(c: u32) -> Weight;
+    fn set_team() -> Weight;
+    fn transfer_ownership() -> Weight;
+    fn freeze() -> Weight;
+    fn thaw() -> Weight;
+    fn grant_mint_allowance() -> Weight;
+    fn revoke_mint_allowance() -> Weight;
+    fn mint_with_voucher(c: u32) -> Weight;
+}
+
+/// A hook that is consulted before ownership of a commodity is assigned to an account, which
+/// allows the receiving account's logic to reject the commodity.
+pub trait OnCommodityReceived<AccountId, CommodityId> {
+    /// Called before the commodity is minted for or transferred to `dest_account`. The previous
+    /// owner is `None` when the commodity is being minted. `data` is the data that was provided to
+    /// [`safe_transfer`](./enum.Call.html#variant.safe_transfer) and is empty otherwise. Return
+    /// an error to reject the commodity.
+    fn on_commodity_received(
+        from_account: Option<&AccountId>,
+        dest_account: &AccountId,
+        commodity_id: &CommodityId,
+        data: &[u8],
+    ) -> DispatchResult;
+}
+
+impl<AccountId, CommodityId> OnCommodityReceived<AccountId, CommodityId> for () {
+    fn on_commodity_received(
+        _from_account: Option<&AccountId>,
+        _dest_account: &AccountId,
+        _commodity_id: &CommodityId,
+        _data: &[u8],
+    ) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Identifies a commodity by the hash of the info that defines it, as calculated by the runtime
+/// system's hashing algorithm. Commodities in collections other than the default collection are
+/// identified by the hash of their collection ID and info. The hashed data begins with a tag that
+/// distinguishes the default collection from every other collection, so the IDs of commodities in
+/// different collections may not collide. Commodities that are defined by equivalent info may not
+/// coexist in the same collection.
+pub struct HashOfInfo<T>(PhantomData<T>);
+
+/// The tag that begins the hashed data of a commodity in the default collection.
+const DEFAULT_COLLECTION_TAG: &[u8; 7] = b"default";
+/// The tag that begins the hashed data of a commodity in any other collection.
+const COLLECTION_TAG: &[u8; 10] = b"collection";
+
+impl<T: frame_system::Trait, CommodityInfo: Encode> GenerateAssetId<CommodityInfo, CommodityId<T>>
+    for HashOfInfo<T>
+{
+    fn generate_asset_id(_index: u128, commodity_info: &CommodityInfo) -> CommodityId<T> {
+        T::Hashing::hash_of(&(DEFAULT_COLLECTION_TAG, commodity_info))
+    }
+
+    fn generate_asset_id_in<CollectionId: Encode>(
+        collection_id: &CollectionId,
+        _index: u128,
+        commodity_info: &CommodityInfo,
+    ) -> CommodityId<T> {
+        T::Hashing::hash_of(&(COLLECTION_TAG, collection_id, commodity_info))
+    }
+}
+
+/// Identifies a commodity by the order in which it was minted. The big-endian index of the
+/// commodity occupies the trailing bytes of its ID and all other bytes are zero.
+pub struct Sequential<T>(PhantomData<T>);
+
+impl<T: frame_system::Trait, CommodityInfo> GenerateAssetId<CommodityInfo, CommodityId<T>>
+    for Sequential<T>
+{
+    fn generate_asset_id(index: u128, _commodity_info: &CommodityInfo) -> CommodityId<T> {
+        let mut commodity_id = CommodityId::<T>::default();
+        let id_bytes = commodity_id.as_mut();
+        let index_bytes = index.to_be_bytes();
+        let len = id_bytes.len().min(index_bytes.len());
+        let id_start = id_bytes.len() - len;
+        id_bytes[id_start..].copy_from_slice(&index_bytes[index_bytes.len() - len..]);
+        commodity_id
+    }
+}
+
+/// Determines which origins may set and clear the attributes of a commodity.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum AttributePolicy {
+    /// Only the commodity owner or an operator for the owner.
+    Owner,
+    /// Only the commodity admin.
+    Admin,
+    /// The commodity owner, an operator for the owner or the commodity admin.
+    OwnerOrAdmin,
+}
+
+/// The operations that may be paused.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Operation {
+    /// Minting commodities.
+    Mint,
+    /// Burning commodities, including forced burns.
+    Burn,
+    /// Transferring commodities by any means, including sales, recoveries and forced transfers.
+    Transfer,
+}
+
 pub trait Trait<I = DefaultInstance>: frame_system::Trait {
     /// The dispatch origin that is able to mint new instances of this type of commodity.
     type CommodityAdmin: EnsureOrigin<Self::Origin>;
     /// The data type that is used to describe this type of commodity.
-    type CommodityInfo: Hashable + Member + Debug + Default + FullCodec + Ord;
-    /// The maximum number of this type of commodity that may exist (minted - burned).
+    type CommodityInfo: Hashable + Member + Debug + Default + FullCodec;
+    /// The maximum number of commodities in the default collection that may exist (minted -
+    /// burned).
     type CommodityLimit: Get<u128>;
-    /// The maximum number of this type of commodity that any single account may own.
+    /// The maximum number of commodities in the default collection that any single account may
+    /// own.
     type UserCommodityLimit: Get<u64>;
+    /// The type that is used to identify collections. The default collection has an ID of zero.
+    type CollectionId: Parameter + Member + AtLeast32BitUnsigned + Default + Copy;
+    /// The maximum length, in bytes, of the metadata of a collection.
+    type MetadataLimit: Get<u32>;
+    /// The amount that is reserved from an account that creates a collection for as long as the
+    /// collection exists.
+    type CollectionDeposit: Get<BalanceOf<Self, I>>;
+    /// The means of generating the ID for a new commodity.
+    type AssetIdGenerator: GenerateAssetId<Self::CommodityInfo, CommodityId<Self>>;
+    /// The maximum length, in bytes, of a commodity attribute key.
+    type KeyLimit: Get<u32>;
+    /// The maximum length, in bytes, of a commodity attribute value.
+    type ValueLimit: Get<u32>;
+    /// The maximum number of attributes that a commodity may have, which bounds the cost of
+    /// removing them when the commodity is burned.
+    type MaxAttributes: Get<u32>;
+    /// The origins that may set and clear commodity attributes.
+    type AttributePolicy: Get<AttributePolicy>;
+    /// The currency in which commodities are sold and storage deposits are held. Bids on
+    /// auctions and offers are reserved.
+    type Currency: ReservableCurrency<Self::AccountId>;
+    /// The maximum number of auctions that may end at any single block.
+    type MaxAuctionsEndingPerBlock: Get<u32>;
+    /// The maximum number of offers that may expire at any single block.
+    type MaxOffersExpiringPerBlock: Get<u32>;
+    /// The set of unique assets for which commodities may be swapped, e.g. this or another
+    /// instance of this pallet.
+    type SwapAssets: UniqueAssets<Self::AccountId, AssetId = CommodityId<Self>>;
+    /// Whether commodity owners may transfer this type of commodity. Non-transferable (soulbound)
+    /// commodities may still be minted, burned and recovered by the commodity admin.
+    type Transferable: Get<bool>;
+    /// The dispatch origin that is able to transfer and burn commodities without the consent of
+    /// their owners, e.g. to enforce a court order, and to pause and unpause operations.
+    type ForceOrigin: EnsureOrigin<Self::Origin>;
+    /// The base amount that is reserved from the owner of a commodity for as long as it exists.
+    type DepositBase: Get<BalanceOf<Self, I>>;
+    /// The amount, per byte of encoded commodity info, that is reserved from the owner of a
+    /// commodity in addition to the base deposit.
+    type DepositPerByte: Get<BalanceOf<Self, I>>;
+    /// The hook that is consulted before a commodity is minted for or transferred to an account.
+    type OnCommodityReceived: OnCommodityReceived<Self::AccountId, CommodityId<Self>>;
+    /// The signature with which the issuer of a collection signs mint vouchers.
+    type VoucherSignature: Parameter + Verify<Signer = Self::VoucherSigner>;
+    /// The public key of a voucher signature, which identifies the account of the issuer.
+    type VoucherSigner: IdentifyAccount<AccountId = Self::AccountId>;
+    /// The priority of unsigned transactions that redeem mint vouchers.
+    type UnsignedPriority: Get<TransactionPriority>;
     type Event: From<Event<Self, I>> + Into<<Self as frame_system::Trait>::Event>;
+    /// Weight information for the dispatchable functions of this pallet.
+    type WeightInfo: WeightInfo;
 }
 
-/// The runtime system's hashing algorithm is used to uniquely identify commodities.
+/// The runtime system's hash type is used to uniquely identify commodities.
 pub type CommodityId<T> = <T as frame_system::Trait>::Hash;
 
+/// The balance type of the currency in which commodities are sold.
+pub type BalanceOf<T, I> =
+    <<T as Trait<I>>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
+
 /// Associates a commodity with its ID.
 pub type Commodity<T, I> = (CommodityId<T>, <T as Trait<I>>::CommodityInfo);
 
+/// The storage layouts of this pallet, used to determine which migrations must be applied.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+    /// Commodities are stored in a sorted list per owner.
+    #[default]
+    V1_0_0,
+    /// Commodity ownership is stored in a double map and commodity info is stored by ID.
+    V2_0_0,
+    /// Commodities are grouped into collections.
+    V3_0_0,
+}
+
 decl_storage! {
     trait Store for Module<T: Trait<I>, I: Instance = DefaultInstance> as Commodity {
         /// The total number of this type of commodity that exists (minted - burned).
@@ -81,10 +599,62 @@ decl_storage! {
         Burned get(fn burned): u128 = 0;
         /// The total number of this type of commodity owned by an account.
         TotalForAccount get(fn total_for_account): map hasher(blake2_128_concat) T::AccountId => u64 = 0;
-        /// A mapping from an account to a list of all of the commodities of this type that are owned by it.
-        CommoditiesForAccount get(fn commodities_for_account): map hasher(blake2_128_concat) T::AccountId => Vec<Commodity<T, I>>;
+        /// An index of the commodities of this type that are owned by each account.
+        CommoditiesForAccount: double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) CommodityId<T> => ();
+        /// A mapping from a commodity ID to the info that defines the commodity.
+        Commodities get(fn commodity_info): map hasher(identity) CommodityId<T> => Option<T::CommodityInfo>;
         /// A mapping from a commodity ID to the account that owns it.
         AccountForCommodity get(fn account_for_commodity): map hasher(identity) CommodityId<T> => T::AccountId;
+        /// A mapping from a commodity ID to the account that is approved to transfer it on behalf of its owner.
+        Approved get(fn approved_for): map hasher(identity) CommodityId<T> => Option<T::AccountId>;
+        /// A mapping from an owner and an account to whether that account may manage all of the owner's commodities.
+        Operators get(fn is_operator): double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) T::AccountId => bool;
+        /// A mapping from a commodity ID and an attribute key to the value of that attribute.
+        Attributes get(fn attribute): double_map hasher(identity) CommodityId<T>, hasher(blake2_128_concat) Vec<u8> => Option<Vec<u8>>;
+        /// The number of attributes that a commodity has.
+        AttributeCount get(fn attribute_count): map hasher(identity) CommodityId<T> => u32;
+        /// A mapping from a commodity ID to the account that is entitled to a royalty when it is sold and the portion of the price it receives.
+        Royalties get(fn royalty): map hasher(identity) CommodityId<T> => Option<(T::AccountId, Perbill)>;
+        /// A mapping from a commodity ID to the price at which it is listed for sale.
+        Listings get(fn listing): map hasher(identity) CommodityId<T> => Option<BalanceOf<T, I>>;
+        /// A mapping from a commodity ID to the auction for that commodity.
+        Auctions get(fn auction): map hasher(identity) CommodityId<T> => Option<AuctionOf<T, I>>;
+        /// A mapping from a commodity ID to the Dutch auction for that commodity.
+        DutchAuctions get(fn dutch_auction): map hasher(identity) CommodityId<T> => Option<DutchAuctionOf<T, I>>;
+        /// A mapping from a block number to the IDs of the commodities whose auctions end at that block.
+        AuctionsEndingAt get(fn auctions_ending_at): map hasher(twox_64_concat) T::BlockNumber => Vec<CommodityId<T>>;
+        /// A mapping from a commodity ID and an account to the offer that account has made for the commodity.
+        Offers get(fn offer): double_map hasher(identity) CommodityId<T>, hasher(blake2_128_concat) T::AccountId => Option<OfferOf<T, I>>;
+        /// A mapping from a block number to the commodity IDs and accounts of the offers that expire at that block.
+        OffersExpiringAt get(fn offers_expiring_at): map hasher(twox_64_concat) T::BlockNumber => Vec<(CommodityId<T>, T::AccountId)>;
+        /// A mapping from a commodity ID to the proposal to swap that commodity for another asset.
+        Swaps get(fn swap): map hasher(identity) CommodityId<T> => Option<SwapOf<T, I>>;
+        /// A mapping from a commodity ID to the named locks that prevent it from being transferred or burned.
+        Locks get(fn locks): map hasher(identity) CommodityId<T> => Vec<LockIdentifier>;
+        /// Whether an operation, or every operation if `None`, is paused.
+        Paused get(fn paused): map hasher(twox_64_concat) Option<Operation> => bool;
+        /// A mapping from a commodity ID to the account from which the deposit for the commodity is reserved and the amount of the deposit.
+        Deposits get(fn deposit): map hasher(identity) CommodityId<T> => Option<(T::AccountId, BalanceOf<T, I>)>;
+        /// A mapping from a collection ID to the collection; the default collection is not stored.
+        Collections get(fn collection): map hasher(twox_64_concat) T::CollectionId => Option<CollectionOf<T, I>>;
+        /// A mapping from a collection ID to the team of accounts that manages the collection.
+        Teams get(fn team): map hasher(twox_64_concat) T::CollectionId => Option<Team<T::AccountId>>;
+        /// The number of collections that have been created, which is also the ID of the latest collection.
+        CollectionCount get(fn collection_count): T::CollectionId;
+        /// A mapping from a commodity ID to the ID of the collection that it belongs to.
+        CollectionForCommodity get(fn collection_for_commodity): map hasher(identity) CommodityId<T> => T::CollectionId;
+        /// An index of the commodities that belong to each collection.
+        CommoditiesInCollection: double_map hasher(twox_64_concat) T::CollectionId, hasher(identity) CommodityId<T> => ();
+        /// The total number of commodities that exist in a collection (minted - burned).
+        TotalInCollection get(fn total_in_collection): map hasher(twox_64_concat) T::CollectionId => u128;
+        /// The total number of commodities in a collection owned by an account.
+        TotalInCollectionForAccount get(fn total_in_collection_for_account): double_map hasher(twox_64_concat) T::CollectionId, hasher(blake2_128_concat) T::AccountId => u64;
+        /// The number of commodities that an account may mint in the default collection without holding the issuer role.
+        MintAllowance get(fn mint_allowance): map hasher(blake2_128_concat) T::AccountId => u32;
+        /// Whether the mint voucher with a nonce has been redeemed in a collection.
+        RedeemedVouchers get(fn voucher_redeemed): double_map hasher(twox_64_concat) T::CollectionId, hasher(twox_64_concat) u64 => bool;
+        /// The storage layout of this pallet; new networks start with the latest layout.
+        StorageVersion get(fn storage_version) build(|_: &GenesisConfig<T, I>| Releases::V3_0_0): Releases;
     }
 
     add_extra_genesis {
@@ -107,6 +677,9 @@ decl_event!(
     where
         CommodityId = <T as frame_system::Trait>::Hash,
         AccountId = <T as frame_system::Trait>::AccountId,
+        Balance = BalanceOf<T, I>,
+        BlockNumber = <T as frame_system::Trait>::BlockNumber,
+        CollectionId = <T as Trait<I>>::CollectionId,
     {
         /// The commodity has been burned.
         Burned(CommodityId),
@@ -114,6 +687,80 @@ decl_event!(
         Minted(CommodityId, AccountId),
         /// Ownership of the commodity has been transferred to the account.
         Transferred(CommodityId, AccountId),
+        /// The account has been approved to transfer the commodity.
+        Approved(CommodityId, AccountId),
+        /// The approval for the commodity has been removed.
+        ApprovalCleared(CommodityId),
+        /// The owner (first account) has authorized the operator (second account) to manage its commodities.
+        OperatorSet(AccountId, AccountId),
+        /// The owner (first account) has revoked the operator's (second account) authorization.
+        OperatorRevoked(AccountId, AccountId),
+        /// The attribute (key) of the commodity has been set to the value.
+        AttributeSet(CommodityId, Vec<u8>, Vec<u8>),
+        /// The attribute (key) of the commodity has been removed.
+        AttributeCleared(CommodityId, Vec<u8>),
+        /// The account is entitled to the portion of the price whenever the commodity is sold.
+        RoyaltySet(CommodityId, AccountId, Perbill),
+        /// A royalty (balance) has been paid to the account for the sale of the commodity.
+        RoyaltyPaid(CommodityId, AccountId, Balance),
+        /// The commodity has been listed for sale at the price.
+        Listed(CommodityId, Balance),
+        /// The listing for the commodity has been removed.
+        ListingCancelled(CommodityId),
+        /// The commodity has been sold by the seller (first account) to the buyer (second account) for the price.
+        Sold(CommodityId, AccountId, AccountId, Balance),
+        /// The commodity is being auctioned with the reserve price until the end of the block.
+        AuctionStarted(CommodityId, Balance, BlockNumber),
+        /// The account has placed the highest bid on the auction for the commodity.
+        BidPlaced(CommodityId, AccountId, Balance),
+        /// The auction for the commodity has ended and is followed by `Sold` if the commodity was sold.
+        AuctionEnded(CommodityId),
+        /// The commodity is being sold in a Dutch auction from the start price (first balance) to the floor price (second
+        /// balance) at the end of the block.
+        DutchAuctionStarted(CommodityId, Balance, Balance, BlockNumber),
+        /// The account has offered to buy the commodity for the amount until the expiry block.
+        OfferMade(CommodityId, AccountId, Balance, BlockNumber),
+        /// The account has withdrawn its offer for the commodity.
+        OfferWithdrawn(CommodityId, AccountId),
+        /// The offer that the account made for the commodity has expired.
+        OfferExpired(CommodityId, AccountId),
+        /// The owner of the commodity (first ID) has proposed to swap it and the payment for the
+        /// wanted asset (second ID).
+        SwapProposed(CommodityId, CommodityId, Balance),
+        /// The swap proposal for the commodity has been removed.
+        SwapCancelled(CommodityId),
+        /// The commodity (first ID) of the proposer (first account) has been swapped for the asset
+        /// (second ID) of the acceptor (second account).
+        Swapped(CommodityId, AccountId, CommodityId, AccountId),
+        /// The force origin has transferred the commodity from its owner (first account) to the second account.
+        ForceTransferred(CommodityId, AccountId, AccountId),
+        /// The force origin has burned the commodity.
+        ForceBurned(CommodityId),
+        /// The operation, or every operation if `None`, has been paused.
+        Paused(Option<Operation>),
+        /// The operation, or every operation if `None`, has been unpaused.
+        Unpaused(Option<Operation>),
+        /// The collection has been created with the account as its owner and every member of its team.
+        CollectionCreated(CollectionId, AccountId),
+        /// The collection has been destroyed.
+        CollectionDestroyed(CollectionId),
+        /// The issuer (first account), admin (second account) and freezer (third account) of the collection have been
+        /// appointed.
+        TeamChanged(CollectionId, AccountId, AccountId, AccountId),
+        /// Ownership of the collection has been transferred to the account.
+        OwnershipTransferred(CollectionId, AccountId),
+        /// The commodity has been frozen.
+        Frozen(CommodityId),
+        /// The commodity has been thawed.
+        Thawed(CommodityId),
+        /// The account may mint the number of commodities in the default collection.
+        MintAllowanceGranted(AccountId, u32),
+        /// The mint allowance of the account has been removed.
+        MintAllowanceRevoked(AccountId),
+        /// The account has used its mint allowance to mint a commodity and may mint the remaining number.
+        MintAllowanceUsed(AccountId, u32),
+        /// The mint voucher with the nonce has been redeemed in the collection.
+        VoucherRedeemed(CollectionId, u64),
     }
 );
 
@@ -123,14 +770,97 @@ decl_error! {
         CommodityExists,
         // Thrown when there is an attempt to burn or transfer a nonexistent commodity.
         NonexistentCommodity,
-        // Thrown when someone who is not the owner of a commodity attempts to transfer or burn it.
+        // Thrown when someone who is neither the owner of a commodity nor an operator for its owner
+        // attempts to transfer or burn it.
         NotCommodityOwner,
-        // Thrown when the commodity admin attempts to mint a commodity and the maximum number of this
-        // type of commodity already exists.
+        // Thrown when someone who is not the owner of a commodity, an operator for its owner or
+        // approved to transfer it attempts to transfer it on behalf of its owner.
+        NotApproved,
+        // Thrown when there is an attempt to mint a commodity and the maximum number of commodities
+        // already exists in its collection.
         TooManyCommodities,
         // Thrown when an attempt is made to mint or transfer a commodity to an account that already
-        // owns the maximum number of this type of commodity.
+        // owns the maximum number of commodities in its collection.
         TooManyCommoditiesForAccount,
+        // Thrown when the receiver hook rejects a commodity that is being minted for or transferred
+        // to an account.
+        CommodityRejected,
+        // Thrown when an attribute key is longer than the configured limit.
+        KeyTooLong,
+        // Thrown when an attribute value is longer than the configured limit.
+        ValueTooLong,
+        // Thrown when an attribute is added to a commodity that already has the maximum number of
+        // attributes.
+        TooManyAttributes,
+        // Thrown when an attempt is made to buy a commodity that is not listed for sale.
+        NotForSale,
+        // Thrown when the price of a commodity is higher than the buyer is willing to pay.
+        PriceTooHigh,
+        // Thrown when an attempt is made to transfer, burn, list or auction a commodity that is
+        // being auctioned.
+        AuctionInProgress,
+        // Thrown when an attempt is made to bid on a commodity that is not being auctioned.
+        NotAuctioned,
+        // Thrown when an auction is started with an end block that is not in the future.
+        InvalidAuctionEnd,
+        // Thrown when an auction is started with an end block at which the maximum number of
+        // auctions already end.
+        TooManyAuctionsEnding,
+        // Thrown when an attempt is made to bid on an auction after its end block.
+        AuctionExpired,
+        // Thrown when a bid does not meet the reserve price or exceed the highest bid.
+        BidTooLow,
+        // Thrown when a Dutch auction is started with a floor price that is above its start price.
+        InvalidAuctionPrices,
+        // Thrown when the owner of a commodity attempts to make an offer for it.
+        OfferByOwner,
+        // Thrown when an offer is made with an expiry block that is not in the future.
+        InvalidOfferExpiry,
+        // Thrown when an offer is made with an expiry block at which the maximum number of offers
+        // already expire.
+        TooManyOffersExpiring,
+        // Thrown when an attempt is made to withdraw or accept an offer that does not exist.
+        NoOffer,
+        // Thrown when an attempt is made to cancel or accept a swap proposal that does not exist.
+        NoSwap,
+        // Thrown when someone who does not own the wanted asset attempts to accept a swap proposal.
+        NotSwapCounterparty,
+        // Thrown when the payment of a swap proposal is lower than the acceptor is willing to take.
+        SwapPaymentTooLow,
+        // Thrown when an operator proposes a swap that pays currency from the commodity owner's
+        // balance, which only the owner may do.
+        PaymentByOperator,
+        // Thrown when there is an attempt to transfer or burn a commodity that is locked.
+        CommodityLocked,
+        // Thrown when there is an attempt to transfer a commodity of a type that is not
+        // transferable.
+        NonTransferable,
+        // Thrown when the force origin attempts to transfer a commodity that is not owned by the
+        // expected account.
+        UnexpectedOwner,
+        // Thrown when there is an attempt to mint, burn or transfer a commodity while that
+        // operation is paused.
+        Paused,
+        // Thrown when there is an attempt to mint a commodity into a collection that does not
+        // exist.
+        NonexistentCollection,
+        // Thrown when someone who does not hold the required role in the team of a collection
+        // attempts to manage the collection or its commodities.
+        NoPermission,
+        // Thrown when the metadata of a collection is longer than the configured limit.
+        MetadataTooLong,
+        // Thrown when a collection is created and every collection ID is in use.
+        NoAvailableCollectionId,
+        // Thrown when someone who is not the owner of a collection attempts to destroy it.
+        NotCollectionOwner,
+        // Thrown when there is an attempt to destroy a collection that contains commodities.
+        CollectionNotEmpty,
+        // Thrown when a mint voucher is redeemed at or after its expiry block.
+        VoucherExpired,
+        // Thrown when there is an attempt to redeem a mint voucher that has already been redeemed.
+        VoucherAlreadyRedeemed,
+        // Thrown when a mint voucher has not been signed by the issuer of its collection.
+        InvalidVoucherSignature,
     }
 }
 
@@ -139,38 +869,65 @@ decl_module! {
         type Error = Error<T, I>;
         fn deposit_event() = default;
 
+        fn on_runtime_upgrade() -> Weight {
+            migration::migrate_to_double_map::<T, I>()
+                .saturating_add(migration::migrate_to_collections::<T, I>())
+        }
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let expired_offers = Self::prune_offers(now);
+            T::WeightInfo::prune_offers(expired_offers).saturating_add(T::WeightInfo::settle_auctions(
+                AuctionsEndingAt::<T, I>::decode_len(now).unwrap_or(0).saturated_into()
+            ))
+        }
+
+        fn on_finalize(now: T::BlockNumber) {
+            Self::settle_auctions(now);
+        }
+
         /// Create a new commodity from the provided commodity info and identify the specified
-        /// account as its owner. The ID of the new commodity will be equal to the hash of the info
-        /// that defines it, as calculated by the runtime system's hashing algorithm.
+        /// account as its owner. The ID of the new commodity is generated by the configured
+        /// `AssetIdGenerator`.
         ///
-        /// The dispatch origin for this call must be the commodity admin.
+        /// The dispatch origin for this call must be the commodity admin, the issuer of the
+        /// default collection or an account with a remaining mint allowance, which is decremented.
         ///
-        /// This function will throw an error if it is called with commodity info that describes
-        /// an existing (duplicate) commodity, if the maximum number of this type of commodity already
-        /// exists or if the specified owner already owns the maximum number of this type of
-        /// commodity.
+        /// This function will throw an error if the generated commodity ID identifies an existing
+        /// (duplicate) commodity, if the maximum number of this type of commodity already
+        /// exists, if the specified owner already owns the maximum number of this type of
+        /// commodity or if the receiver hook rejects the commodity.
+        ///
+        /// The storage deposit for the commodity is reserved from the caller if the call is signed,
+        /// as it is by an issuer or an account whose allowance is used, or otherwise from the owner
+        /// of the commodity.
         ///
         /// - `owner_account`: Receiver of the commodity.
         /// - `commodity_info`: The information that defines the commodity.
-        #[weight = 10_000]
+        #[weight = T::WeightInfo::mint(Module::<T, I>::owned_by(owner_account))]
         pub fn mint(origin, owner_account: T::AccountId, commodity_info: T::CommodityInfo) -> dispatch::DispatchResult {
-            T::CommodityAdmin::ensure_origin(origin)?;
+            let minter = ensure_signed(origin.clone()).ok();
+            let allowance_account = Self::ensure_minter(origin)?;
 
-            let commodity_id = <Self as UniqueAssets<_>>::mint(&owner_account, commodity_info)?;
+            let commodity_id = Self::do_mint(&Zero::zero(), minter.as_ref(), &owner_account, commodity_info)?;
             Self::deposit_event(RawEvent::Minted(commodity_id, owner_account.clone()));
+            if let Some(account) = allowance_account {
+                let remaining = Self::use_mint_allowance(&account);
+                Self::deposit_event(RawEvent::MintAllowanceUsed(account, remaining));
+            }
+
             Ok(())
         }
 
         /// Destroy the specified commodity.
         ///
-        /// The dispatch origin for this call must be the commodity owner.
+        /// The dispatch origin for this call must be the commodity owner or an operator for the
+        /// owner.
         ///
-        /// - `commodity_id`: The hash (calculated by the runtime system's hashing algorithm)
-        ///   of the info that defines the commodity to destroy.
-        #[weight = 10_000]
+        /// - `commodity_id`: The ID of the commodity to destroy.
+        #[weight = T::WeightInfo::burn(Module::<T, I>::owned_by_owner_of(commodity_id))]
         pub fn burn(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(who == Self::account_for_commodity(&commodity_id), Error::<T, I>::NotCommodityOwner);
+            ensure!(Self::is_owner_or_operator(&who, &commodity_id), Error::<T, I>::NotCommodityOwner);
 
             <Self as UniqueAssets<_>>::burn(&commodity_id)?;
             Self::deposit_event(RawEvent::Burned(commodity_id.clone()));
@@ -179,144 +936,1431 @@ decl_module! {
 
         /// Transfer a commodity to a new owner.
         ///
-        /// The dispatch origin for this call must be the commodity owner.
+        /// The dispatch origin for this call must be the commodity owner or an operator for the
+        /// owner.
         ///
         /// This function will throw an error if the new owner already owns the maximum
-        /// number of this type of commodity.
+        /// number of this type of commodity or if the receiver hook rejects the commodity.
         ///
         /// - `dest_account`: Receiver of the commodity.
-        /// - `commodity_id`: The hash (calculated by the runtime system's hashing algorithm)
-        ///   of the info that defines the commodity to destroy.
-        #[weight = 10_000]
+        /// - `commodity_id`: The ID of the commodity to transfer.
+        #[weight = T::WeightInfo::transfer(Module::<T, I>::owned_by_owner_of(commodity_id))]
         pub fn transfer(origin, dest_account: T::AccountId, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(who == Self::account_for_commodity(&commodity_id), Error::<T, I>::NotCommodityOwner);
+            ensure!(Self::is_owner_or_operator(&who, &commodity_id), Error::<T, I>::NotCommodityOwner);
 
             <Self as UniqueAssets<_>>::transfer(&dest_account, &commodity_id)?;
             Self::deposit_event(RawEvent::Transferred(commodity_id.clone(), dest_account.clone()));
             Ok(())
         }
-    }
-}
 
-impl<T: Trait<I>, I: Instance> UniqueAssets<T::AccountId> for Module<T, I> {
-    type AssetId = CommodityId<T>;
-    type AssetInfo = T::CommodityInfo;
-    type AssetLimit = T::CommodityLimit;
-    type UserAssetLimit = T::UserCommodityLimit;
+        /// Approve an account to transfer a commodity on behalf of its owner. Any existing
+        /// approval for the commodity is replaced. Approvals are cleared when the commodity is
+        /// transferred or burned.
+        ///
+        /// The dispatch origin for this call must be the commodity owner or an operator for the
+        /// owner.
+        ///
+        /// - `approved_account`: The account that may transfer the commodity.
+        /// - `commodity_id`: The ID of the commodity to approve.
+        #[weight = T::WeightInfo::approve()]
+        pub fn approve(origin, approved_account: T::AccountId, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &commodity_id), Error::<T, I>::NotCommodityOwner);
 
-    fn total() -> u128 {
-        Self::total()
-    }
+            <Self as ApprovableUniqueAssets<_>>::approve(&approved_account, &commodity_id)?;
+            Self::deposit_event(RawEvent::Approved(commodity_id, approved_account));
+            Ok(())
+        }
 
-    fn burned() -> u128 {
-        Self::burned()
-    }
+        /// Remove the approval for a commodity.
+        ///
+        /// The dispatch origin for this call must be the commodity owner or an operator for the
+        /// owner.
+        ///
+        /// - `commodity_id`: The ID of the commodity.
+        #[weight = T::WeightInfo::clear_approval()]
+        pub fn clear_approval(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &commodity_id), Error::<T, I>::NotCommodityOwner);
 
-    fn total_for_account(account: &T::AccountId) -> u64 {
-        Self::total_for_account(account)
-    }
+            <Self as ApprovableUniqueAssets<_>>::clear_approval(&commodity_id)?;
+            Self::deposit_event(RawEvent::ApprovalCleared(commodity_id));
+            Ok(())
+        }
 
-    fn assets_for_account(account: &T::AccountId) -> Vec<Commodity<T, I>> {
-        Self::commodities_for_account(account)
-    }
+        /// Transfer a commodity to a new owner on behalf of its current owner.
+        ///
+        /// The dispatch origin for this call must be the commodity owner, an operator for the owner
+        /// or the account that is approved to transfer the commodity.
+        ///
+        /// This function will throw an error if the new owner already owns the maximum
+        /// number of this type of commodity or if the receiver hook rejects the commodity.
+        ///
+        /// - `dest_account`: Receiver of the commodity.
+        /// - `commodity_id`: The ID of the commodity to transfer.
+        #[weight = T::WeightInfo::transfer_from(Module::<T, I>::owned_by_owner_of(commodity_id))]
+        pub fn transfer_from(origin, dest_account: T::AccountId, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::is_owner_or_operator(&who, &commodity_id)
+                    || Some(&who) == Self::approved_for(commodity_id).as_ref(),
+                Error::<T, I>::NotApproved
+            );
 
-    fn owner_of(commodity_id: &CommodityId<T>) -> T::AccountId {
-        Self::account_for_commodity(commodity_id)
-    }
+            <Self as UniqueAssets<_>>::transfer(&dest_account, &commodity_id)?;
+            Self::deposit_event(RawEvent::Transferred(commodity_id, dest_account));
+            Ok(())
+        }
 
-    fn mint(
-        owner_account: &T::AccountId,
-        commodity_info: <T as Trait<I>>::CommodityInfo,
-    ) -> dispatch::result::Result<CommodityId<T>, dispatch::DispatchError> {
-        let commodity_id = T::Hashing::hash_of(&commodity_info);
+        /// Transfer a commodity to a new owner and provide data to the receiver hook, which may use
+        /// it to decide whether to accept the commodity.
+        ///
+        /// The dispatch origin for this call must be the commodity owner, an operator for the owner
+        /// or the account that is approved to transfer the commodity.
+        ///
+        /// This function will throw an error if the new owner already owns the maximum
+        /// number of this type of commodity or if the receiver hook rejects the commodity.
+        ///
+        /// - `dest_account`: Receiver of the commodity.
+        /// - `commodity_id`: The ID of the commodity to transfer.
+        /// - `data`: Data for the receiver hook.
+        #[weight = T::WeightInfo::safe_transfer(Module::<T, I>::owned_by_owner_of(commodity_id))]
+        pub fn safe_transfer(origin, dest_account: T::AccountId, commodity_id: CommodityId<T>, data: Vec<u8>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::is_owner_or_operator(&who, &commodity_id)
+                    || Some(&who) == Self::approved_for(commodity_id).as_ref(),
+                Error::<T, I>::NotApproved
+            );
 
-        ensure!(
-            !AccountForCommodity::<T, I>::contains_key(&commodity_id),
-            Error::<T, I>::CommodityExists
-        );
+            Self::do_transfer(&dest_account, &commodity_id, &data)?;
+            Self::deposit_event(RawEvent::Transferred(commodity_id, dest_account));
+            Ok(())
+        }
 
-        ensure!(
-            Self::total_for_account(owner_account) < T::UserCommodityLimit::get(),
-            Error::<T, I>::TooManyCommoditiesForAccount
-        );
+        /// Authorize an account to manage all of the caller's commodities of this type. An
+        /// operator may burn, transfer and approve any commodity owned by the caller until its
+        /// authorization is revoked.
+        ///
+        /// - `operator_account`: The account to authorize.
+        #[weight = T::WeightInfo::set_operator()]
+        pub fn set_operator(origin, operator_account: T::AccountId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
 
-        ensure!(
-            Self::total() < T::CommodityLimit::get(),
-            Error::<T, I>::TooManyCommodities
-        );
+            <Self as ApprovableUniqueAssets<_>>::set_operator(&who, &operator_account);
+            Self::deposit_event(RawEvent::OperatorSet(who, operator_account));
+            Ok(())
+        }
 
-        let new_commodity = (commodity_id, commodity_info);
+        /// Revoke an account's authorization to manage the caller's commodities of this type.
+        ///
+        /// - `operator_account`: The account whose authorization to revoke.
+        #[weight = T::WeightInfo::revoke_operator()]
+        pub fn revoke_operator(origin, operator_account: T::AccountId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
 
-        Total::<I>::mutate(|total| *total += 1);
-        TotalForAccount::<T, I>::mutate(owner_account, |total| *total += 1);
-        CommoditiesForAccount::<T, I>::mutate(owner_account, |commodities| {
-            match commodities.binary_search(&new_commodity) {
-                Ok(_pos) => {} // should never happen
-                Err(pos) => commodities.insert(pos, new_commodity),
+            <Self as ApprovableUniqueAssets<_>>::revoke_operator(&who, &operator_account);
+            Self::deposit_event(RawEvent::OperatorRevoked(who, operator_account));
+            Ok(())
+        }
+
+        /// Create a batch of new commodities from the provided commodity info, each for its
+        /// specified owner. If any of the commodities cannot be created, none are created.
+        ///
+        /// The dispatch origin for this call must be the commodity admin or the issuer of the
+        /// default collection.
+        ///
+        /// This function will throw an error in any of the cases described for `mint`.
+        ///
+        /// - `commodities`: The receiver of each commodity and the information that defines it.
+        #[weight = T::WeightInfo::mint_batch(commodities.len().saturated_into())]
+        pub fn mint_batch(origin, commodities: Vec<(T::AccountId, T::CommodityInfo)>) -> dispatch::DispatchResult {
+            let minter = ensure_signed(origin.clone()).ok();
+            Self::ensure_role(origin, &Zero::zero(), Role::Issuer)?;
+
+            let owners = commodities.iter().map(|(owner, _)| owner.clone()).collect::<Vec<_>>();
+            let commodity_ids = Self::do_mint_batch(minter.as_ref(), commodities)?;
+            for (commodity_id, owner_account) in commodity_ids.into_iter().zip(owners) {
+                Self::deposit_event(RawEvent::Minted(commodity_id, owner_account));
             }
-        });
-        AccountForCommodity::<T, I>::insert(commodity_id, &owner_account);
 
-        Ok(commodity_id)
-    }
+            Ok(())
+        }
 
-    fn burn(commodity_id: &CommodityId<T>) -> dispatch::DispatchResult {
-        let owner = Self::owner_of(commodity_id);
-        ensure!(
-            owner != T::AccountId::default(),
-            Error::<T, I>::NonexistentCommodity
-        );
+        /// Destroy a batch of commodities. If any of the commodities cannot be destroyed, none are
+        /// destroyed.
+        ///
+        /// The dispatch origin for this call must be the owner of each commodity or an operator
+        /// for its owner.
+        ///
+        /// - `commodity_ids`: The IDs of the commodities to destroy.
+        #[weight = T::WeightInfo::burn_batch(commodity_ids.len().saturated_into())]
+        pub fn burn_batch(origin, commodity_ids: Vec<CommodityId<T>>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            for commodity_id in commodity_ids.iter() {
+                ensure!(Self::is_owner_or_operator(&who, commodity_id), Error::<T, I>::NotCommodityOwner);
+            }
 
-        let burn_commodity = (*commodity_id, <T as Trait<I>>::CommodityInfo::default());
+            <Self as UniqueAssets<_>>::burn_batch(&commodity_ids)?;
+            for commodity_id in commodity_ids {
+                Self::deposit_event(RawEvent::Burned(commodity_id));
+            }
 
-        Total::<I>::mutate(|total| *total -= 1);
-        Burned::<I>::mutate(|total| *total += 1);
-        TotalForAccount::<T, I>::mutate(&owner, |total| *total -= 1);
-        CommoditiesForAccount::<T, I>::mutate(owner, |commodities| {
-            let pos = commodities
-                .binary_search(&burn_commodity)
-                .expect("We already checked that we have the correct owner; qed");
-            commodities.remove(pos);
-        });
-        AccountForCommodity::<T, I>::remove(&commodity_id);
+            Ok(())
+        }
 
-        Ok(())
-    }
+        /// Transfer a batch of commodities to a new owner. If any of the commodities cannot be
+        /// transferred, none are transferred.
+        ///
+        /// The dispatch origin for this call must be the owner of each commodity or an operator
+        /// for its owner.
+        ///
+        /// This function will throw an error in any of the cases described for `transfer`.
+        ///
+        /// - `dest_account`: Receiver of the commodities.
+        /// - `commodity_ids`: The IDs of the commodities to transfer.
+        #[weight = T::WeightInfo::transfer_batch(commodity_ids.len().saturated_into())]
+        pub fn transfer_batch(origin, dest_account: T::AccountId, commodity_ids: Vec<CommodityId<T>>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            for commodity_id in commodity_ids.iter() {
+                ensure!(Self::is_owner_or_operator(&who, commodity_id), Error::<T, I>::NotCommodityOwner);
+            }
 
-    fn transfer(
-        dest_account: &T::AccountId,
-        commodity_id: &CommodityId<T>,
-    ) -> dispatch::DispatchResult {
-        let owner = Self::owner_of(&commodity_id);
-        ensure!(
-            owner != T::AccountId::default(),
-            Error::<T, I>::NonexistentCommodity
-        );
+            <Self as UniqueAssets<_>>::transfer_batch(&dest_account, &commodity_ids)?;
+            for commodity_id in commodity_ids {
+                Self::deposit_event(RawEvent::Transferred(commodity_id, dest_account.clone()));
+            }
 
-        ensure!(
-            Self::total_for_account(dest_account) < T::UserCommodityLimit::get(),
-            Error::<T, I>::TooManyCommoditiesForAccount
-        );
+            Ok(())
+        }
 
-        let xfer_commodity = (*commodity_id, <T as Trait<I>>::CommodityInfo::default());
+        /// Set the value of a commodity attribute, replacing any existing value.
+        ///
+        /// The dispatch origin for this call must be allowed by the attribute policy.
+        ///
+        /// This function will throw an error if the key or value is longer than the configured
+        /// limit or if a new attribute is added to a commodity that already has the maximum number
+        /// of attributes.
+        ///
+        /// - `commodity_id`: The ID of the commodity.
+        /// - `key`: The attribute key.
+        /// - `value`: The attribute value.
+        #[weight = T::WeightInfo::set_attribute()]
+        pub fn set_attribute(origin, commodity_id: CommodityId<T>, key: Vec<u8>, value: Vec<u8>) -> dispatch::DispatchResult {
+            Self::ensure_attribute_origin(origin, &commodity_id)?;
+            ensure!(key.len() <= T::KeyLimit::get() as usize, Error::<T, I>::KeyTooLong);
+            ensure!(value.len() <= T::ValueLimit::get() as usize, Error::<T, I>::ValueTooLong);
 
-        TotalForAccount::<T, I>::mutate(&owner, |total| *total -= 1);
-        TotalForAccount::<T, I>::mutate(dest_account, |total| *total += 1);
-        let commodity = CommoditiesForAccount::<T, I>::mutate(owner, |commodities| {
-            let pos = commodities
-                .binary_search(&xfer_commodity)
-                .expect("We already checked that we have the correct owner; qed");
-            commodities.remove(pos)
-        });
-        CommoditiesForAccount::<T, I>::mutate(dest_account, |commodities| {
-            match commodities.binary_search(&commodity) {
-                Ok(_pos) => {} // should never happen
-                Err(pos) => commodities.insert(pos, commodity),
+            if !Attributes::<T, I>::contains_key(commodity_id, &key) {
+                ensure!(Self::attribute_count(commodity_id) < T::MaxAttributes::get(), Error::<T, I>::TooManyAttributes);
+                AttributeCount::<T, I>::mutate(commodity_id, |count| *count += 1);
             }
-        });
-        AccountForCommodity::<T, I>::insert(&commodity_id, &dest_account);
+            Attributes::<T, I>::insert(commodity_id, &key, &value);
+            Self::deposit_event(RawEvent::AttributeSet(commodity_id, key, value));
+            Ok(())
+        }
 
-        Ok(())
+        /// Remove a commodity attribute, if it exists.
+        ///
+        /// The dispatch origin for this call must be allowed by the attribute policy.
+        ///
+        /// - `commodity_id`: The ID of the commodity.
+        /// - `key`: The attribute key.
+        #[weight = T::WeightInfo::clear_attribute()]
+        pub fn clear_attribute(origin, commodity_id: CommodityId<T>, key: Vec<u8>) -> dispatch::DispatchResult {
+            Self::ensure_attribute_origin(origin, &commodity_id)?;
+
+            if Attributes::<T, I>::take(commodity_id, &key).is_some() {
+                AttributeCount::<T, I>::mutate_exists(commodity_id, |count| {
+                    *count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+                });
+            }
+            Self::deposit_event(RawEvent::AttributeCleared(commodity_id, key));
+            Ok(())
+        }
+
+        /// Create a new commodity from the provided commodity info and identify the specified
+        /// account as its owner. The royalty recipient is entitled to the specified portion of the
+        /// price whenever the commodity is sold.
+        ///
+        /// The dispatch origin for this call must be the commodity admin or the issuer of the
+        /// default collection.
+        ///
+        /// This function will throw an error in any of the cases described for `mint`.
+        ///
+        /// - `owner_account`: Receiver of the commodity.
+        /// - `commodity_info`: The information that defines the commodity.
+        /// - `royalty_account`: Receiver of the royalty.
+        /// - `royalty_rate`: The portion of the price that is paid to the royalty recipient.
+        #[weight = T::WeightInfo::mint_with_royalty(Module::<T, I>::owned_by(owner_account))]
+        pub fn mint_with_royalty(
+            origin,
+            owner_account: T::AccountId,
+            commodity_info: T::CommodityInfo,
+            royalty_account: T::AccountId,
+            royalty_rate: Perbill,
+        ) -> dispatch::DispatchResult {
+            let minter = ensure_signed(origin.clone()).ok();
+            Self::ensure_role(origin, &Zero::zero(), Role::Issuer)?;
+
+            let commodity_id = Self::do_mint(&Zero::zero(), minter.as_ref(), &owner_account, commodity_info)?;
+            Royalties::<T, I>::insert(commodity_id, (&royalty_account, royalty_rate));
+            Self::deposit_event(RawEvent::Minted(commodity_id, owner_account));
+            Self::deposit_event(RawEvent::RoyaltySet(commodity_id, royalty_account, royalty_rate));
+            Ok(())
+        }
+
+        /// List a commodity for sale at a fixed price, replacing any existing listing. Listings
+        /// are removed when the commodity is transferred or burned.
+        ///
+        /// The dispatch origin for this call must be the commodity owner or an operator for the
+        /// owner.
+        ///
+        /// - `commodity_id`: The ID of the commodity to list.
+        /// - `price`: The price at which the commodity may be bought.
+        #[weight = T::WeightInfo::list_for_sale()]
+        pub fn list_for_sale(origin, commodity_id: CommodityId<T>, price: BalanceOf<T, I>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &commodity_id), Error::<T, I>::NotCommodityOwner);
+            Self::ensure_not_auctioned(&commodity_id)?;
+
+            Listings::<T, I>::insert(commodity_id, price);
+            Self::deposit_event(RawEvent::Listed(commodity_id, price));
+            Ok(())
+        }
+
+        /// Remove the listing for a commodity.
+        ///
+        /// The dispatch origin for this call must be the commodity owner or an operator for the
+        /// owner.
+        ///
+        /// - `commodity_id`: The ID of the commodity to delist.
+        #[weight = T::WeightInfo::cancel_listing()]
+        pub fn cancel_listing(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &commodity_id), Error::<T, I>::NotCommodityOwner);
+            ensure!(Listings::<T, I>::contains_key(commodity_id), Error::<T, I>::NotForSale);
+
+            Listings::<T, I>::remove(commodity_id);
+            Self::deposit_event(RawEvent::ListingCancelled(commodity_id));
+            Ok(())
+        }
+
+        /// Buy a commodity that is listed for sale or that is being sold in a Dutch auction. The
+        /// listing price or the current auction price is paid to the commodity owner, less any
+        /// royalty, and ownership of the commodity is transferred to the caller. Either both
+        /// happen or neither does. Buying a commodity closes its Dutch auction.
+        ///
+        /// This function will throw an error if the price is higher than the maximum price, if
+        /// the caller is unable to pay the price or in any of the cases described for `transfer`.
+        ///
+        /// - `commodity_id`: The ID of the commodity to buy.
+        /// - `max_price`: The maximum price that the caller is willing to pay.
+        #[weight = T::WeightInfo::buy(Module::<T, I>::owned_by_owner_of(commodity_id))]
+        pub fn buy(origin, commodity_id: CommodityId<T>, max_price: BalanceOf<T, I>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            let price = Self::dutch_auction_price(&commodity_id)
+                .or_else(|| Self::listing(commodity_id))
+                .ok_or(Error::<T, I>::NotForSale)?;
+            ensure!(price <= max_price, Error::<T, I>::PriceTooHigh);
+
+            let seller = Self::account_for_commodity(commodity_id);
+            Self::sell_at_auction_price(&seller, &who, &commodity_id, price)?;
+            Self::deposit_event(RawEvent::Sold(commodity_id, seller, who, price));
+            Ok(())
+        }
+
+        /// Auction a commodity. Bids must meet the reserve price and exceed the highest bid. At
+        /// the end of the end block, the commodity is sold to the highest bidder for the amount of
+        /// its bid. Any listing for the commodity is removed, and the commodity may not be
+        /// transferred, burned or listed for sale while it is being auctioned.
+        ///
+        /// The dispatch origin for this call must be the commodity owner or an operator for the
+        /// owner.
+        ///
+        /// This function will throw an error if the commodity is already being auctioned, if the
+        /// end block is not in the future or if the maximum number of auctions already end at the
+        /// end block.
+        ///
+        /// - `commodity_id`: The ID of the commodity to auction.
+        /// - `reserve_price`: The lowest bid that will be accepted.
+        /// - `end`: The block at the end of which the auction will be settled.
+        #[weight = T::WeightInfo::start_auction()]
+        pub fn start_auction(origin, commodity_id: CommodityId<T>, reserve_price: BalanceOf<T, I>, end: T::BlockNumber) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &commodity_id), Error::<T, I>::NotCommodityOwner);
+            Self::ensure_not_auctioned(&commodity_id)?;
+
+            let seller = Self::account_for_commodity(commodity_id);
+            Self::open_english_auction(&seller, &commodity_id, reserve_price, end)?;
+            Self::deposit_event(RawEvent::AuctionStarted(commodity_id, reserve_price, end));
+            Ok(())
+        }
+
+        /// Bid on an auction. The amount of the bid is reserved until the caller is outbid or the
+        /// auction ends.
+        ///
+        /// This function will throw an error if the auction has ended, if the bid does not meet
+        /// the reserve price or exceed the highest bid or if the caller is unable to reserve the
+        /// amount of the bid.
+        ///
+        /// - `commodity_id`: The ID of the commodity that is being auctioned.
+        /// - `amount`: The amount of the bid.
+        #[weight = T::WeightInfo::bid()]
+        pub fn bid(origin, commodity_id: CommodityId<T>, amount: BalanceOf<T, I>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::place_bid(&who, &commodity_id, amount)?;
+            Self::deposit_event(RawEvent::BidPlaced(commodity_id, who, amount));
+            Ok(())
+        }
+
+        /// Sell a commodity in a Dutch auction. The price decays linearly with each block from
+        /// the start price to the floor price, which is reached at the end block, and the first
+        /// account to `buy` the commodity pays the current price. Any listing for the commodity is
+        /// removed, and the commodity may not be transferred, burned or listed for sale while it
+        /// is being auctioned. The auction is closed at the end of the end block if the commodity
+        /// has not been bought.
+        ///
+        /// The dispatch origin for this call must be the commodity owner or an operator for the
+        /// owner.
+        ///
+        /// This function will throw an error if the commodity is already being auctioned, if the
+        /// floor price is above the start price, if the end block is not in the future or if the
+        /// maximum number of auctions already end at the end block.
+        ///
+        /// - `commodity_id`: The ID of the commodity to auction.
+        /// - `start_price`: The price of the commodity when the auction starts.
+        /// - `floor_price`: The price of the commodity at the end block.
+        /// - `end`: The block at the end of which the auction will be closed.
+        #[weight = T::WeightInfo::start_dutch_auction()]
+        pub fn start_dutch_auction(
+            origin,
+            commodity_id: CommodityId<T>,
+            start_price: BalanceOf<T, I>,
+            floor_price: BalanceOf<T, I>,
+            end: T::BlockNumber,
+        ) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &commodity_id), Error::<T, I>::NotCommodityOwner);
+            Self::ensure_not_auctioned(&commodity_id)?;
+
+            let seller = Self::account_for_commodity(commodity_id);
+            Self::open_dutch_auction(&seller, &commodity_id, start_price, floor_price, end)?;
+            Self::deposit_event(RawEvent::DutchAuctionStarted(commodity_id, start_price, floor_price, end));
+            Ok(())
+        }
+
+        /// Offer to buy a commodity, whether or not it is listed for sale. The amount of the offer
+        /// is reserved until the offer is withdrawn, accepted or expires, and replaces any
+        /// previous offer that the caller has made for the commodity.
+        ///
+        /// This function will throw an error if the commodity does not exist, if the caller owns
+        /// the commodity, if the expiry block is not in the future, if the maximum number of offers
+        /// already expire at the expiry block or if the caller is unable to reserve the amount of
+        /// the offer.
+        ///
+        /// - `commodity_id`: The ID of the commodity to buy.
+        /// - `amount`: The amount that the caller will pay for the commodity.
+        /// - `expiry`: The block at the start of which the offer expires.
+        #[weight = T::WeightInfo::make_offer()]
+        pub fn make_offer(origin, commodity_id: CommodityId<T>, amount: BalanceOf<T, I>, expiry: T::BlockNumber) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::place_offer(&who, &commodity_id, amount, expiry)?;
+            Self::deposit_event(RawEvent::OfferMade(commodity_id, who, amount, expiry));
+            Ok(())
+        }
+
+        /// Withdraw an offer to buy a commodity, releasing its amount.
+        ///
+        /// - `commodity_id`: The ID of the commodity for which the caller made the offer.
+        #[weight = T::WeightInfo::withdraw_offer()]
+        pub fn withdraw_offer(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::withdraw(&who, &commodity_id)?;
+            Self::deposit_event(RawEvent::OfferWithdrawn(commodity_id, who));
+            Ok(())
+        }
+
+        /// Accept an offer to buy a commodity. The amount of the offer is paid to the commodity
+        /// owner, less any royalty, and ownership of the commodity is transferred to the bidder.
+        /// Either both happen or neither does.
+        ///
+        /// The dispatch origin for this call must be the commodity owner or an operator for the
+        /// owner.
+        ///
+        /// This function will throw an error if the bidder has not made an offer for the
+        /// commodity, if the commodity is being auctioned or in any of the cases described for
+        /// `transfer`.
+        ///
+        /// - `commodity_id`: The ID of the commodity to sell.
+        /// - `bidder`: The account that made the offer.
+        #[weight = T::WeightInfo::accept_offer(Module::<T, I>::owned_by_owner_of(commodity_id))]
+        pub fn accept_offer(origin, commodity_id: CommodityId<T>, bidder: T::AccountId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &commodity_id), Error::<T, I>::NotCommodityOwner);
+            Self::ensure_not_auctioned(&commodity_id)?;
+
+            let seller = Self::account_for_commodity(commodity_id);
+            let amount = Self::settle_offer(&seller, &bidder, &commodity_id)?;
+            Self::deposit_event(RawEvent::Sold(commodity_id, seller, bidder, amount));
+            Ok(())
+        }
+
+        /// Propose to swap a commodity, and optionally an amount of currency, for an asset from
+        /// the configured swap assets. The proposal replaces any previous proposal for the
+        /// commodity and is removed when the commodity is transferred or burned.
+        ///
+        /// The dispatch origin for this call must be the commodity owner or an operator for the
+        /// owner. Only the owner may propose a swap with a payment, since the payment is made from
+        /// the owner's balance.
+        ///
+        /// This function will throw an error if the commodity is being auctioned or if an
+        /// operator proposes a payment.
+        ///
+        /// - `commodity_id`: The ID of the commodity to give.
+        /// - `wanted`: The ID of the asset to receive in exchange.
+        /// - `payment`: The amount of currency to pay in addition to the commodity, which may be
+        ///   zero.
+        #[weight = T::WeightInfo::propose_swap()]
+        pub fn propose_swap(origin, commodity_id: CommodityId<T>, wanted: CommodityId<T>, payment: BalanceOf<T, I>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &commodity_id), Error::<T, I>::NotCommodityOwner);
+            Self::ensure_not_auctioned(&commodity_id)?;
+            let proposer = Self::account_for_commodity(commodity_id);
+            ensure!(payment.is_zero() || who == proposer, Error::<T, I>::PaymentByOperator);
+
+            Swaps::<T, I>::insert(commodity_id, Swap { proposer, wanted, payment });
+            Self::deposit_event(RawEvent::SwapProposed(commodity_id, wanted, payment));
+            Ok(())
+        }
+
+        /// Remove the swap proposal for a commodity.
+        ///
+        /// The dispatch origin for this call must be the commodity owner or an operator for the
+        /// owner.
+        ///
+        /// - `commodity_id`: The ID of the commodity that was offered in the swap.
+        #[weight = T::WeightInfo::cancel_swap()]
+        pub fn cancel_swap(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_owner_or_operator(&who, &commodity_id), Error::<T, I>::NotCommodityOwner);
+            ensure!(Swaps::<T, I>::contains_key(commodity_id), Error::<T, I>::NoSwap);
+
+            Swaps::<T, I>::remove(commodity_id);
+            Self::deposit_event(RawEvent::SwapCancelled(commodity_id));
+            Ok(())
+        }
+
+        /// Accept a swap proposal. The commodity and the payment are transferred to the caller and
+        /// the wanted asset is transferred to the proposer. Either all of the transfers happen or
+        /// none do.
+        ///
+        /// The dispatch origin for this call must be the owner of the wanted asset.
+        ///
+        /// This function will throw an error if the payment is lower than the minimum payment, if
+        /// the commodity is being auctioned, if the proposer is unable to pay or if either asset
+        /// cannot be transferred.
+        ///
+        /// - `commodity_id`: The ID of the commodity that was offered in the swap.
+        /// - `min_payment`: The lowest payment that the caller is willing to take, which protects
+        ///   it from a proposal that has been replaced.
+        #[weight = T::WeightInfo::accept_swap(Module::<T, I>::owned_by_owner_of(commodity_id))]
+        pub fn accept_swap(origin, commodity_id: CommodityId<T>, min_payment: BalanceOf<T, I>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            let swap = Self::swap(commodity_id).ok_or(Error::<T, I>::NoSwap)?;
+            ensure!(T::SwapAssets::owner_of(&swap.wanted) == who, Error::<T, I>::NotSwapCounterparty);
+            ensure!(swap.payment >= min_payment, Error::<T, I>::SwapPaymentTooLow);
+
+            Self::exchange(&who, &commodity_id, &swap)?;
+            Self::deposit_event(RawEvent::Swapped(commodity_id, swap.proposer, swap.wanted, who));
+            Ok(())
+        }
+
+        /// Transfer a commodity to a new owner on behalf of its current owner, e.g. to recover a
+        /// commodity from an account whose keys have been lost. Commodities may be recovered
+        /// even if this type of commodity is not transferable.
+        ///
+        /// The dispatch origin for this call must be the commodity admin.
+        ///
+        /// This function will throw an error if the commodity does not exist, if it is locked, if
+        /// the new owner already owns the maximum number of this type of commodity or if the
+        /// receiver hook rejects the commodity.
+        ///
+        /// - `dest_account`: Receiver of the commodity.
+        /// - `commodity_id`: The ID of the commodity to recover.
+        #[weight = T::WeightInfo::recover(Module::<T, I>::owned_by_owner_of(commodity_id))]
+        pub fn recover(origin, dest_account: T::AccountId, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            Self::reassign(&dest_account, &commodity_id, &[])?;
+            Self::deposit_event(RawEvent::Transferred(commodity_id, dest_account));
+            Ok(())
+        }
+
+        /// Transfer a commodity to a new owner without the consent of its current owner, even if
+        /// this type of commodity is not transferable.
+        ///
+        /// The dispatch origin for this call must be the force origin or the admin of the
+        /// commodity's collection.
+        ///
+        /// This function will throw an error if the commodity is not owned by the expected owner,
+        /// if it is locked, if the new owner already owns the maximum number of this type of
+        /// commodity or if the receiver hook rejects the commodity.
+        ///
+        /// - `from_expected`: The account that is expected to own the commodity.
+        /// - `dest_account`: Receiver of the commodity.
+        /// - `commodity_id`: The ID of the commodity to transfer.
+        #[weight = T::WeightInfo::force_transfer(Module::<T, I>::owned_by_owner_of(commodity_id))]
+        pub fn force_transfer(origin, from_expected: T::AccountId, dest_account: T::AccountId, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            Self::ensure_role(origin, &Self::collection_for_commodity(commodity_id), Role::Admin)?;
+            ensure!(Self::account_for_commodity(commodity_id) == from_expected, Error::<T, I>::UnexpectedOwner);
+
+            Self::reassign(&dest_account, &commodity_id, &[])?;
+            Self::deposit_event(RawEvent::ForceTransferred(commodity_id, from_expected, dest_account));
+            Ok(())
+        }
+
+        /// Destroy a commodity without the consent of its owner.
+        ///
+        /// The dispatch origin for this call must be the force origin or the admin of the
+        /// commodity's collection.
+        ///
+        /// This function will throw an error if the commodity does not exist or if it is locked.
+        ///
+        /// - `commodity_id`: The ID of the commodity to destroy.
+        #[weight = T::WeightInfo::force_burn(Module::<T, I>::owned_by_owner_of(commodity_id))]
+        pub fn force_burn(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            Self::ensure_role(origin, &Self::collection_for_commodity(commodity_id), Role::Admin)?;
+
+            <Self as UniqueAssets<_>>::burn(&commodity_id)?;
+            Self::deposit_event(RawEvent::ForceBurned(commodity_id));
+            Ok(())
+        }
+
+        /// Pause an operation, or every operation, e.g. during an incident. While an operation is
+        /// paused, every function that performs it, including the methods of `UniqueAssets`,
+        /// will throw an error. Operations that are paused on their own remain paused after every
+        /// operation has been unpaused, and vice versa.
+        ///
+        /// The dispatch origin for this call must be the force origin.
+        ///
+        /// - `operation`: The operation to pause, or `None` to pause every operation.
+        #[weight = T::WeightInfo::pause()]
+        pub fn pause(origin, operation: Option<Operation>) -> dispatch::DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            Paused::<I>::insert(operation, true);
+            Self::deposit_event(RawEvent::Paused(operation));
+            Ok(())
+        }
+
+        /// Unpause an operation, or every operation, that has been paused.
+        ///
+        /// The dispatch origin for this call must be the force origin.
+        ///
+        /// - `operation`: The operation to unpause, or `None` to unpause every operation.
+        #[weight = T::WeightInfo::unpause()]
+        pub fn unpause(origin, operation: Option<Operation>) -> dispatch::DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            Paused::<I>::remove(operation);
+            Self::deposit_event(RawEvent::Unpaused(operation));
+            Ok(())
+        }
+
+        /// Create a collection of commodities with its own limits and metadata. The caller owns
+        /// the collection and holds every role of its team, and the collection deposit is reserved
+        /// from the caller until the collection is destroyed. The ID of the new collection is the
+        /// number of collections that have been created.
+        ///
+        /// The dispatch origin for this call must be _Signed_.
+        ///
+        /// This function will throw an error if the metadata is longer than the configured limit
+        /// or if the caller is unable to reserve the collection deposit.
+        ///
+        /// - `max_commodities`: The maximum number of commodities that may exist in the collection.
+        /// - `max_commodities_per_account`: The maximum number of commodities in the collection
+        ///   that any single account may own.
+        /// - `metadata`: Arbitrary data that describes the collection.
+        #[weight = T::WeightInfo::create_collection()]
+        pub fn create_collection(
+            origin,
+            max_commodities: u128,
+            max_commodities_per_account: u64,
+            metadata: Vec<u8>,
+        ) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let collection_id = <Self as UniqueCollections<_>>::create_collection(
+                &who,
+                max_commodities,
+                max_commodities_per_account,
+                metadata,
+            )?;
+            Self::deposit_event(RawEvent::CollectionCreated(collection_id, who));
+            Ok(())
+        }
+
+        /// Create a collection of commodities with its own owner, limits and metadata without
+        /// reserving a deposit. The owner of the collection holds every role of its team.
+        ///
+        /// The dispatch origin for this call must be the commodity admin.
+        ///
+        /// This function will throw an error if the metadata is longer than the configured limit.
+        ///
+        /// - `owner_account`: The account that owns the collection.
+        /// - `max_commodities`: The maximum number of commodities that may exist in the collection.
+        /// - `max_commodities_per_account`: The maximum number of commodities in the collection
+        ///   that any single account may own.
+        /// - `metadata`: Arbitrary data that describes the collection.
+        #[weight = T::WeightInfo::force_create_collection()]
+        pub fn force_create_collection(
+            origin,
+            owner_account: T::AccountId,
+            max_commodities: u128,
+            max_commodities_per_account: u64,
+            metadata: Vec<u8>,
+        ) -> dispatch::DispatchResult {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            let collection_id = Self::open_collection(
+                &owner_account,
+                Zero::zero(),
+                max_commodities,
+                max_commodities_per_account,
+                metadata,
+            )?;
+            Self::deposit_event(RawEvent::CollectionCreated(collection_id, owner_account));
+            Ok(())
+        }
+
+        /// Destroy a collection that has no commodities, releasing its deposit.
+        ///
+        /// The dispatch origin for this call must be the owner of the collection.
+        ///
+        /// This function will throw an error if the collection contains any commodities.
+        ///
+        /// - `collection_id`: The ID of the collection to destroy.
+        #[weight = T::WeightInfo::destroy_collection()]
+        pub fn destroy_collection(origin, collection_id: T::CollectionId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_collection_owner(&who, &collection_id)?;
+
+            <Self as UniqueCollections<_>>::destroy_collection(&collection_id)?;
+            Self::deposit_event(RawEvent::CollectionDestroyed(collection_id));
+            Ok(())
+        }
+
+        /// Create a new commodity in a collection from the provided commodity info and identify
+        /// the specified account as its owner.
+        ///
+        /// The dispatch origin for this call must be the issuer of the collection, or the
+        /// commodity admin in the case of the default collection.
+        ///
+        /// This function will throw an error if the collection does not exist, if the generated
+        /// commodity ID identifies an existing (duplicate) commodity, if the maximum number of
+        /// commodities already exists in the collection, if the specified owner already owns the
+        /// maximum number of commodities in the collection or if the receiver hook rejects the
+        /// commodity.
+        ///
+        /// The storage deposit for the commodity is reserved from the caller if the call is signed,
+        /// or otherwise from the owner of the commodity.
+        ///
+        /// - `collection_id`: The ID of the collection.
+        /// - `owner_account`: Receiver of the commodity.
+        /// - `commodity_info`: The information that defines the commodity.
+        #[weight = T::WeightInfo::mint_into(Module::<T, I>::owned_by(owner_account))]
+        pub fn mint_into(
+            origin,
+            collection_id: T::CollectionId,
+            owner_account: T::AccountId,
+            commodity_info: T::CommodityInfo,
+        ) -> dispatch::DispatchResult {
+            let minter = ensure_signed(origin.clone()).ok();
+            Self::ensure_role(origin, &collection_id, Role::Issuer)?;
+
+            let commodity_id = Self::do_mint(&collection_id, minter.as_ref(), &owner_account, commodity_info)?;
+            Self::deposit_event(RawEvent::Minted(commodity_id, owner_account));
+            Ok(())
+        }
+
+        /// Appoint the team of accounts that manages a collection, replacing its current team.
+        ///
+        /// The dispatch origin for this call must be the owner of the collection, or the
+        /// commodity admin in the case of the default collection.
+        ///
+        /// - `collection_id`: The ID of the collection.
+        /// - `issuer`: The account that may mint commodities in the collection.
+        /// - `admin`: The account that may transfer and burn commodities in the collection
+        ///   without the consent of their owners.
+        /// - `freezer`: The account that may freeze and thaw commodities in the collection.
+        #[weight = T::WeightInfo::set_team()]
+        pub fn set_team(
+            origin,
+            collection_id: T::CollectionId,
+            issuer: T::AccountId,
+            admin: T::AccountId,
+            freezer: T::AccountId,
+        ) -> dispatch::DispatchResult {
+            Self::ensure_team_appointer(origin, &collection_id)?;
+
+            Teams::<T, I>::insert(collection_id, Team {
+                issuer: issuer.clone(),
+                admin: admin.clone(),
+                freezer: freezer.clone(),
+            });
+            Self::deposit_event(RawEvent::TeamChanged(collection_id, issuer, admin, freezer));
+            Ok(())
+        }
+
+        /// Transfer ownership of a collection to another account. The deposit for the collection
+        /// is reserved from the new owner in place of the current owner.
+        ///
+        /// The dispatch origin for this call must be the owner of the collection.
+        ///
+        /// This function will throw an error if the new owner does not exist.
+        ///
+        /// - `collection_id`: The ID of the collection.
+        /// - `new_owner`: The account that will own the collection.
+        #[weight = T::WeightInfo::transfer_ownership()]
+        pub fn transfer_ownership(origin, collection_id: T::CollectionId, new_owner: T::AccountId) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_collection_owner(&who, &collection_id)?;
+
+            Self::reassign_collection(&collection_id, &new_owner)?;
+            Self::deposit_event(RawEvent::OwnershipTransferred(collection_id, new_owner));
+            Ok(())
+        }
+
+        /// Freeze a commodity so that it may not be transferred or burned until it is thawed.
+        ///
+        /// The dispatch origin for this call must be the force origin or the freezer of the
+        /// commodity's collection.
+        ///
+        /// - `commodity_id`: The ID of the commodity to freeze.
+        #[weight = T::WeightInfo::freeze()]
+        pub fn freeze(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            Self::ensure_role(origin, &Self::collection_for_commodity(commodity_id), Role::Freezer)?;
+
+            <Self as LockableUniqueAssets<_>>::lock(&commodity_id, FREEZE_LOCK)?;
+            Self::deposit_event(RawEvent::Frozen(commodity_id));
+            Ok(())
+        }
+
+        /// Thaw a commodity that has been frozen. The commodity remains locked if any other lock
+        /// is held on it.
+        ///
+        /// The dispatch origin for this call must be the force origin or the freezer of the
+        /// commodity's collection.
+        ///
+        /// - `commodity_id`: The ID of the commodity to thaw.
+        #[weight = T::WeightInfo::thaw()]
+        pub fn thaw(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            Self::ensure_role(origin, &Self::collection_for_commodity(commodity_id), Role::Freezer)?;
+            ensure!(AccountForCommodity::<T, I>::contains_key(commodity_id), Error::<T, I>::NonexistentCommodity);
+
+            <Self as LockableUniqueAssets<_>>::unlock(&commodity_id, FREEZE_LOCK);
+            Self::deposit_event(RawEvent::Thawed(commodity_id));
+            Ok(())
+        }
+
+        /// Allow an account to mint a number of commodities in the default collection by way of
+        /// `mint`, replacing any allowance that it already has.
+        ///
+        /// The dispatch origin for this call must be the commodity admin.
+        ///
+        /// - `account`: The account that may mint commodities.
+        /// - `count`: The number of commodities that the account may mint.
+        #[weight = T::WeightInfo::grant_mint_allowance()]
+        pub fn grant_mint_allowance(origin, account: T::AccountId, count: u32) -> dispatch::DispatchResult {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            MintAllowance::<T, I>::insert(&account, count);
+            Self::deposit_event(RawEvent::MintAllowanceGranted(account, count));
+            Ok(())
+        }
+
+        /// Remove the mint allowance of an account.
+        ///
+        /// The dispatch origin for this call must be the commodity admin.
+        ///
+        /// - `account`: The account whose allowance is removed.
+        #[weight = T::WeightInfo::revoke_mint_allowance()]
+        pub fn revoke_mint_allowance(origin, account: T::AccountId) -> dispatch::DispatchResult {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            MintAllowance::<T, I>::remove(&account);
+            Self::deposit_event(RawEvent::MintAllowanceRevoked(account));
+            Ok(())
+        }
+
+        /// Create the commodity of a mint voucher and identify the recipient of the voucher as
+        /// its owner. The storage deposit for the commodity is reserved from the caller or, if the
+        /// call is unsigned, from the issuer that signed the voucher.
+        ///
+        /// The dispatch origin for this call may be signed by any account or none, in which case
+        /// the voucher is validated before the transaction is included in a block.
+        ///
+        /// This function will throw an error if the voucher has expired or has already been
+        /// redeemed, if it has not been signed by the issuer of its collection or if the commodity
+        /// may not be minted into the collection.
+        ///
+        /// - `voucher`: The voucher that describes the commodity.
+        /// - `signature`: The issuer's signature of the voucher and the pallet instance prefix.
+        #[weight = T::WeightInfo::mint_with_voucher(Module::<T, I>::owned_by(&voucher.recipient))]
+        pub fn mint_with_voucher(
+            origin,
+            voucher: MintVoucherOf<T, I>,
+            signature: T::VoucherSignature,
+        ) -> dispatch::DispatchResult {
+            let caller = match ensure_signed(origin.clone()) {
+                Ok(who) => Some(who),
+                Err(_) => ensure_none(origin).map(|_| None)?,
+            };
+            let issuer = Self::check_voucher(&voucher, &signature)?;
+
+            let (collection_id, nonce, recipient) = (voucher.collection_id, voucher.nonce, voucher.recipient.clone());
+            let commodity_id = Self::redeem_voucher(voucher, &caller.unwrap_or(issuer))?;
+            Self::deposit_event(RawEvent::Minted(commodity_id, recipient));
+            Self::deposit_event(RawEvent::VoucherRedeemed(collection_id, nonce));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// Pay the seller of a commodity the price at which it is sold, less any royalty, which is
+    /// paid to the royalty recipient. Either the full price is paid or nothing is paid. Every
+    /// sale path should use this function to pay for a commodity before transferring it.
+    #[transactional]
+    pub fn pay_for_commodity(
+        buyer: &T::AccountId,
+        seller: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+        price: BalanceOf<T, I>,
+    ) -> DispatchResult {
+        let royalty = <Self as RoyaltyBearingUniqueAssets<_, _>>::royalty_info(commodity_id, price)
+            .filter(|(_, royalty)| !royalty.is_zero());
+        let proceeds = match royalty {
+            Some((_, royalty)) => price.saturating_sub(royalty),
+            None => price,
+        };
+
+        // The body of a transactional function may not use the `?` operator.
+        match royalty {
+            Some((royalty_account, royalty)) => T::Currency::transfer(
+                buyer,
+                &royalty_account,
+                royalty,
+                ExistenceRequirement::KeepAlive,
+            )
+            .map(|_| {
+                Self::deposit_event(RawEvent::RoyaltyPaid(
+                    *commodity_id,
+                    royalty_account,
+                    royalty,
+                ))
+            }),
+            None => Ok(()),
+        }
+        .and_then(|_| {
+            T::Currency::transfer(buyer, seller, proceeds, ExistenceRequirement::KeepAlive)
+        })
+    }
+
+    /// Whether an account is the owner of a commodity or an operator for its owner.
+    fn is_owner_or_operator(who: &T::AccountId, commodity_id: &CommodityId<T>) -> bool {
+        let owner = Self::account_for_commodity(commodity_id);
+        *who == owner || Self::is_operator(&owner, who)
+    }
+
+    /// The number of commodities owned by an account, used to weigh dispatchable functions.
+    fn owned_by(account: &T::AccountId) -> u32 {
+        Self::total_for_account(account).saturated_into()
+    }
+
+    /// The number of commodities owned by the owner of a commodity, used to weigh dispatchable
+    /// functions.
+    fn owned_by_owner_of(commodity_id: &CommodityId<T>) -> u32 {
+        Self::owned_by(&Self::account_for_commodity(commodity_id))
+    }
+
+    /// Pay for a commodity and transfer it from the seller to the buyer. Either both happen or
+    /// neither does.
+    #[transactional]
+    fn sell(
+        seller: &T::AccountId,
+        buyer: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+        price: BalanceOf<T, I>,
+    ) -> DispatchResult {
+        Self::pay_for_commodity(buyer, seller, commodity_id, price)
+            .and_then(|_| <Self as UniqueAssets<_>>::transfer(buyer, commodity_id))
+    }
+
+    /// The deposit that is reserved from the owner of a commodity that is defined by the provided
+    /// info, which is proportional to the encoded size of the info.
+    pub fn deposit_for(commodity_info: &T::CommodityInfo) -> BalanceOf<T, I> {
+        let bytes: BalanceOf<T, I> = commodity_info.encode().len().saturated_into();
+        T::DepositBase::get().saturating_add(T::DepositPerByte::get().saturating_mul(bytes))
+    }
+
+    /// Ensure that the origin may mint a commodity in the default collection by way of `mint`.
+    /// Returns the account whose mint allowance must be used if the origin does not hold the issuer
+    /// role.
+    fn ensure_minter(
+        origin: T::Origin,
+    ) -> dispatch::result::Result<Option<T::AccountId>, dispatch::DispatchError> {
+        let err = match Self::ensure_role(origin.clone(), &Zero::zero(), Role::Issuer) {
+            Ok(()) => return Ok(None),
+            Err(err) => err,
+        };
+
+        match ensure_signed(origin) {
+            Ok(who) if Self::mint_allowance(&who) > 0 => Ok(Some(who)),
+            _ => Err(err),
+        }
+    }
+
+    /// Decrement the mint allowance of an account, removing it once it has been used up. Returns
+    /// the number of commodities that the account may still mint.
+    fn use_mint_allowance(account: &T::AccountId) -> u32 {
+        MintAllowance::<T, I>::mutate_exists(account, |allowance| {
+            let remaining = allowance.unwrap_or(0).saturating_sub(1);
+            *allowance = Some(remaining).filter(|remaining| *remaining > 0);
+            remaining
+        })
+    }
+
+    /// Ensure that an operation is paused neither on its own nor by a pause of every operation.
+    fn ensure_not_paused(operation: Operation) -> DispatchResult {
+        ensure!(
+            !Self::paused(None::<Operation>) && !Self::paused(Some(operation)),
+            Error::<T, I>::Paused
+        );
+        Ok(())
+    }
+
+    /// Ensure that a commodity is not being auctioned.
+    fn ensure_not_auctioned(commodity_id: &CommodityId<T>) -> DispatchResult {
+        ensure!(
+            !Auctions::<T, I>::contains_key(commodity_id)
+                && !DutchAuctions::<T, I>::contains_key(commodity_id),
+            Error::<T, I>::AuctionInProgress
+        );
+        Ok(())
+    }
+
+    /// Ensure that the origin may manage the attributes of an existing commodity according to the
+    /// attribute policy.
+    fn ensure_attribute_origin(origin: T::Origin, commodity_id: &CommodityId<T>) -> DispatchResult {
+        ensure!(
+            AccountForCommodity::<T, I>::contains_key(commodity_id),
+            Error::<T, I>::NonexistentCommodity
+        );
+
+        let origin = match T::AttributePolicy::get() {
+            AttributePolicy::Owner => origin,
+            AttributePolicy::Admin => {
+                T::CommodityAdmin::ensure_origin(origin)?;
+                return Ok(());
+            }
+            AttributePolicy::OwnerOrAdmin => match T::CommodityAdmin::try_origin(origin) {
+                Ok(_) => return Ok(()),
+                Err(origin) => origin,
+            },
+        };
+
+        let who = ensure_signed(origin)?;
+        ensure!(
+            Self::is_owner_or_operator(&who, commodity_id),
+            Error::<T, I>::NotCommodityOwner
+        );
+        Ok(())
+    }
+
+    /// Transfer ownership of a commodity after consulting the receiver hook with the provided data.
+    fn do_transfer(
+        dest_account: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+        data: &[u8],
+    ) -> DispatchResult {
+        ensure!(T::Transferable::get(), Error::<T, I>::NonTransferable);
+        Self::reassign(dest_account, commodity_id, data)
+    }
+
+    /// Assign ownership of a commodity to an account after consulting the receiver hook with the
+    /// provided data, whether or not commodities of this type are transferable.
+    fn reassign(
+        dest_account: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+        data: &[u8],
+    ) -> DispatchResult {
+        Self::ensure_not_paused(Operation::Transfer)?;
+
+        let owner = Self::owner_of(commodity_id);
+        ensure!(
+            owner != T::AccountId::default(),
+            Error::<T, I>::NonexistentCommodity
+        );
+        ensure!(
+            !<Self as LockableUniqueAssets<_>>::is_locked(commodity_id),
+            Error::<T, I>::CommodityLocked
+        );
+        Self::ensure_not_auctioned(commodity_id)?;
+
+        let collection_id = Self::collection_for_commodity(commodity_id);
+        let (_, max_per_account) =
+            Self::collection_limits(&collection_id).ok_or(Error::<T, I>::NonexistentCollection)?;
+        ensure!(
+            Self::total_in_collection_for_account(collection_id, dest_account) < max_per_account,
+            Error::<T, I>::TooManyCommoditiesForAccount
+        );
+
+        T::OnCommodityReceived::on_commodity_received(
+            Some(&owner),
+            dest_account,
+            commodity_id,
+            data,
+        )
+        .map_err(|_| Error::<T, I>::CommodityRejected)?;
+
+        // The deposit is held by the account that minted or last received the commodity.
+        if let Some((depositor, deposit)) = Self::deposit(commodity_id) {
+            T::Currency::repatriate_reserved(
+                &depositor,
+                dest_account,
+                deposit,
+                BalanceStatus::Reserved,
+            )?;
+            Deposits::<T, I>::insert(commodity_id, (dest_account, deposit));
+        }
+
+        TotalForAccount::<T, I>::mutate(&owner, |total| *total -= 1);
+        TotalForAccount::<T, I>::mutate(dest_account, |total| *total += 1);
+        TotalInCollectionForAccount::<T, I>::mutate(collection_id, &owner, |total| *total -= 1);
+        TotalInCollectionForAccount::<T, I>::mutate(collection_id, dest_account, |total| {
+            *total += 1
+        });
+        CommoditiesForAccount::<T, I>::remove(&owner, commodity_id);
+        CommoditiesForAccount::<T, I>::insert(dest_account, commodity_id, ());
+        AccountForCommodity::<T, I>::insert(commodity_id, dest_account);
+        Approved::<T, I>::remove(commodity_id);
+        Listings::<T, I>::remove(commodity_id);
+        Swaps::<T, I>::remove(commodity_id);
+
+        Ok(())
+    }
+
+    /// Ensure that a commodity that is defined by the provided info may be minted in a collection
+    /// for an account, subject to the limits of that collection. Returns the ID of the commodity.
+    pub(crate) fn ensure_can_mint(
+        collection_id: &T::CollectionId,
+        owner_account: &T::AccountId,
+        commodity_info: &T::CommodityInfo,
+    ) -> dispatch::result::Result<CommodityId<T>, dispatch::DispatchError> {
+        Self::ensure_not_paused(Operation::Mint)?;
+
+        // The number of commodities that have ever been minted.
+        let index = Self::total().wrapping_add(Self::burned());
+        let commodity_id = if collection_id.is_zero() {
+            T::AssetIdGenerator::generate_asset_id(index, commodity_info)
+        } else {
+            T::AssetIdGenerator::generate_asset_id_in(collection_id, index, commodity_info)
+        };
+
+        ensure!(
+            !AccountForCommodity::<T, I>::contains_key(&commodity_id),
+            Error::<T, I>::CommodityExists
+        );
+
+        let (max_commodities, max_per_account) =
+            Self::collection_limits(collection_id).ok_or(Error::<T, I>::NonexistentCollection)?;
+        ensure!(
+            Self::total_in_collection_for_account(collection_id, owner_account) < max_per_account,
+            Error::<T, I>::TooManyCommoditiesForAccount
+        );
+
+        ensure!(
+            Self::total_in_collection(collection_id) < max_commodities,
+            Error::<T, I>::TooManyCommodities
+        );
+
+        T::OnCommodityReceived::on_commodity_received(None, owner_account, &commodity_id, &[])
+            .map_err(|_| Error::<T, I>::CommodityRejected)?;
+
+        Ok(commodity_id)
+    }
+
+    /// Create a new commodity in a collection, subject to the limits of that collection. The
+    /// deposit for the commodity is reserved from the account that mints it or, if it is minted by
+    /// an origin without an account, from its owner.
+    fn do_mint(
+        collection_id: &T::CollectionId,
+        minter: Option<&T::AccountId>,
+        owner_account: &T::AccountId,
+        commodity_info: T::CommodityInfo,
+    ) -> dispatch::result::Result<CommodityId<T>, dispatch::DispatchError> {
+        let commodity_id = Self::ensure_can_mint(collection_id, owner_account, &commodity_info)?;
+
+        let depositor = minter.unwrap_or(owner_account);
+        let deposit = Self::deposit_for(&commodity_info);
+        T::Currency::reserve(depositor, deposit)?;
+        if !deposit.is_zero() {
+            Deposits::<T, I>::insert(commodity_id, (depositor, deposit));
+        }
+
+        Total::<I>::mutate(|total| *total += 1);
+        TotalForAccount::<T, I>::mutate(owner_account, |total| *total += 1);
+        CommoditiesForAccount::<T, I>::insert(owner_account, commodity_id, ());
+        Commodities::<T, I>::insert(commodity_id, commodity_info);
+        AccountForCommodity::<T, I>::insert(commodity_id, &owner_account);
+        TotalInCollection::<T, I>::mutate(collection_id, |total| *total += 1);
+        TotalInCollectionForAccount::<T, I>::mutate(collection_id, owner_account, |total| {
+            *total += 1
+        });
+        CommoditiesInCollection::<T, I>::insert(collection_id, commodity_id, ());
+        if !collection_id.is_zero() {
+            CollectionForCommodity::<T, I>::insert(commodity_id, collection_id);
+        }
+
+        Ok(commodity_id)
+    }
+
+    /// Create a batch of new commodities in the default collection, reserving their deposits as
+    /// described for `do_mint`. If any of the commodities cannot be created, none are created.
+    #[transactional]
+    fn do_mint_batch(
+        minter: Option<&T::AccountId>,
+        commodities: Vec<(T::AccountId, T::CommodityInfo)>,
+    ) -> dispatch::result::Result<Vec<CommodityId<T>>, dispatch::DispatchError> {
+        // The body of a transactional function may not use the `?` operator.
+        commodities
+            .into_iter()
+            .map(|(owner_account, commodity_info)| {
+                Self::do_mint(&Zero::zero(), minter, &owner_account, commodity_info)
+            })
+            .collect::<dispatch::result::Result<Vec<_>, _>>()
+    }
+}
+
+impl<T: Trait<I>, I: Instance> UniqueAssets<T::AccountId> for Module<T, I> {
+    type AssetId = CommodityId<T>;
+    type AssetInfo = T::CommodityInfo;
+    type AssetLimit = T::CommodityLimit;
+    type UserAssetLimit = T::UserCommodityLimit;
+
+    fn total() -> u128 {
+        Self::total()
+    }
+
+    fn burned() -> u128 {
+        Self::burned()
+    }
+
+    fn total_for_account(account: &T::AccountId) -> u64 {
+        Self::total_for_account(account)
+    }
+
+    fn assets_for_account(account: &T::AccountId) -> Vec<Commodity<T, I>> {
+        CommoditiesForAccount::<T, I>::iter_prefix(account)
+            .filter_map(|(commodity_id, _)| {
+                Self::commodity_info(commodity_id)
+                    .map(|commodity_info| (commodity_id, commodity_info))
+            })
+            .collect()
+    }
+
+    fn owner_of(commodity_id: &CommodityId<T>) -> T::AccountId {
+        Self::account_for_commodity(commodity_id)
+    }
+
+    fn mint(
+        owner_account: &T::AccountId,
+        commodity_info: <T as Trait<I>>::CommodityInfo,
+    ) -> dispatch::result::Result<CommodityId<T>, dispatch::DispatchError> {
+        Self::do_mint(&Zero::zero(), None, owner_account, commodity_info)
+    }
+
+    fn burn(commodity_id: &CommodityId<T>) -> dispatch::DispatchResult {
+        Self::ensure_not_paused(Operation::Burn)?;
+
+        let owner = Self::owner_of(commodity_id);
+        ensure!(
+            owner != T::AccountId::default(),
+            Error::<T, I>::NonexistentCommodity
+        );
+        ensure!(
+            !<Self as LockableUniqueAssets<_>>::is_locked(commodity_id),
+            Error::<T, I>::CommodityLocked
+        );
+        Self::ensure_not_auctioned(commodity_id)?;
+
+        if let Some((depositor, deposit)) = Deposits::<T, I>::take(commodity_id) {
+            T::Currency::unreserve(&depositor, deposit);
+        }
+
+        let collection_id = CollectionForCommodity::<T, I>::take(commodity_id);
+        TotalInCollection::<T, I>::mutate(collection_id, |total| *total -= 1);
+        TotalInCollectionForAccount::<T, I>::mutate(collection_id, &owner, |total| *total -= 1);
+        CommoditiesInCollection::<T, I>::remove(collection_id, commodity_id);
+
+        Total::<I>::mutate(|total| *total -= 1);
+        Burned::<I>::mutate(|total| *total += 1);
+        TotalForAccount::<T, I>::mutate(&owner, |total| *total -= 1);
+        CommoditiesForAccount::<T, I>::remove(&owner, commodity_id);
+        Commodities::<T, I>::remove(commodity_id);
+        AccountForCommodity::<T, I>::remove(&commodity_id);
+        Approved::<T, I>::remove(commodity_id);
+        Attributes::<T, I>::remove_prefix(commodity_id);
+        AttributeCount::<T, I>::remove(commodity_id);
+        Royalties::<T, I>::remove(commodity_id);
+        Listings::<T, I>::remove(commodity_id);
+        Swaps::<T, I>::remove(commodity_id);
+
+        Ok(())
+    }
+
+    fn transfer(
+        dest_account: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+    ) -> dispatch::DispatchResult {
+        Self::do_transfer(dest_account, commodity_id, &[])
+    }
+}
+
+impl<T: Trait<I>, I: Instance> RoyaltyBearingUniqueAssets<T::AccountId, BalanceOf<T, I>>
+    for Module<T, I>
+{
+    fn royalty_info(
+        commodity_id: &CommodityId<T>,
+        sale_price: BalanceOf<T, I>,
+    ) -> Option<(T::AccountId, BalanceOf<T, I>)> {
+        Self::royalty(commodity_id)
+            .map(|(royalty_account, royalty_rate)| (royalty_account, royalty_rate * sale_price))
+    }
+}
+
+impl<T: Trait<I>, I: Instance> ApprovableUniqueAssets<T::AccountId> for Module<T, I> {
+    fn approved_for(commodity_id: &CommodityId<T>) -> Option<T::AccountId> {
+        Self::approved_for(commodity_id)
+    }
+
+    fn approve(
+        approved_account: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+    ) -> dispatch::DispatchResult {
+        ensure!(
+            AccountForCommodity::<T, I>::contains_key(commodity_id),
+            Error::<T, I>::NonexistentCommodity
+        );
+
+        Approved::<T, I>::insert(commodity_id, approved_account);
+
+        Ok(())
+    }
+
+    fn clear_approval(commodity_id: &CommodityId<T>) -> dispatch::DispatchResult {
+        ensure!(
+            AccountForCommodity::<T, I>::contains_key(commodity_id),
+            Error::<T, I>::NonexistentCommodity
+        );
+
+        Approved::<T, I>::remove(commodity_id);
+
+        Ok(())
+    }
+
+    fn is_operator(owner_account: &T::AccountId, operator_account: &T::AccountId) -> bool {
+        Self::is_operator(owner_account, operator_account)
+    }
+
+    fn set_operator(owner_account: &T::AccountId, operator_account: &T::AccountId) {
+        Operators::<T, I>::insert(owner_account, operator_account, true);
+    }
+
+    fn revoke_operator(owner_account: &T::AccountId, operator_account: &T::AccountId) {
+        Operators::<T, I>::remove(owner_account, operator_account);
+    }
+}
+
+impl<T: Trait<I>, I: Instance> LockableUniqueAssets<T::AccountId> for Module<T, I> {
+    fn is_locked(commodity_id: &CommodityId<T>) -> bool {
+        Locks::<T, I>::contains_key(commodity_id)
+    }
+
+    fn lock(commodity_id: &CommodityId<T>, lock_id: LockIdentifier) -> dispatch::DispatchResult {
+        ensure!(
+            AccountForCommodity::<T, I>::contains_key(commodity_id),
+            Error::<T, I>::NonexistentCommodity
+        );
+
+        Locks::<T, I>::mutate(commodity_id, |locks| {
+            if !locks.contains(&lock_id) {
+                locks.push(lock_id);
+            }
+        });
+
+        Ok(())
+    }
+
+    fn unlock(commodity_id: &CommodityId<T>, lock_id: LockIdentifier) {
+        let mut locks = Self::locks(commodity_id);
+        locks.retain(|id| *id != lock_id);
+        if locks.is_empty() {
+            Locks::<T, I>::remove(commodity_id);
+        } else {
+            Locks::<T, I>::insert(commodity_id, locks);
+        }
+    }
+}
+
+impl<T: Trait<I>, I: Instance> UniqueCollections<T::AccountId> for Module<T, I> {
+    type CollectionId = T::CollectionId;
+
+    fn collection_of(commodity_id: &CommodityId<T>) -> T::CollectionId {
+        Self::collection_for_commodity(commodity_id)
+    }
+
+    fn total_in_collection(collection_id: &T::CollectionId) -> u128 {
+        Self::total_in_collection(collection_id)
+    }
+
+    fn total_in_collection_for_account(
+        collection_id: &T::CollectionId,
+        account: &T::AccountId,
+    ) -> u64 {
+        Self::total_in_collection_for_account(collection_id, account)
+    }
+
+    fn create_collection(
+        owner_account: &T::AccountId,
+        max_commodities: u128,
+        max_commodities_per_account: u64,
+        metadata: Vec<u8>,
+    ) -> dispatch::result::Result<T::CollectionId, dispatch::DispatchError> {
+        Self::open_collection(
+            owner_account,
+            T::CollectionDeposit::get(),
+            max_commodities,
+            max_commodities_per_account,
+            metadata,
+        )
+    }
+
+    fn destroy_collection(collection_id: &T::CollectionId) -> dispatch::DispatchResult {
+        Self::close_collection(collection_id)
+    }
+
+    fn mint_into(
+        collection_id: &T::CollectionId,
+        owner_account: &T::AccountId,
+        commodity_info: T::CommodityInfo,
+    ) -> dispatch::result::Result<CommodityId<T>, dispatch::DispatchError> {
+        Self::do_mint(collection_id, None, owner_account, commodity_info)
     }
 }
@@ -0,0 +1,68 @@
+//! Storage migrations for the commodities pallet.
+
+use super::*;
+use frame_support::{storage::migration::StorageKeyIterator, weights::Weight, Blake2_128Concat};
+
+/// Migrate from storing a sorted list of commodities per account to storing commodity ownership
+/// in a double map and commodity info in a map that is keyed by commodity ID.
+pub fn migrate_to_double_map<T: Trait<I>, I: Instance>() -> Weight {
+    if Module::<T, I>::storage_version() != Releases::V1_0_0 {
+        return 0;
+    }
+
+    // The new ownership index shares its storage prefix with the old per-account lists, so the
+    // old lists must be completely drained before any new entries are written.
+    let old_commodities =
+        StorageKeyIterator::<T::AccountId, Vec<Commodity<T, I>>, Blake2_128Concat>::new(
+            I::PREFIX.as_bytes(),
+            b"CommoditiesForAccount",
+        )
+        .drain()
+        .collect::<Vec<_>>();
+
+    // Account for reading and writing the storage version.
+    let mut reads = 1;
+    let mut writes = 1;
+    for (account, commodities) in old_commodities {
+        reads += 1;
+        writes += 1;
+        for (commodity_id, commodity_info) in commodities {
+            CommoditiesForAccount::<T, I>::insert(&account, commodity_id, ());
+            Commodities::<T, I>::insert(commodity_id, commodity_info);
+            writes += 2;
+        }
+    }
+
+    StorageVersion::<I>::put(Releases::V2_0_0);
+
+    T::DbWeight::get().reads_writes(reads, writes)
+}
+
+/// Migrate to grouping commodities into collections by adding every existing commodity to the
+/// default collection.
+pub fn migrate_to_collections<T: Trait<I>, I: Instance>() -> Weight {
+    if Module::<T, I>::storage_version() != Releases::V2_0_0 {
+        return 0;
+    }
+
+    let default_collection = T::CollectionId::zero();
+
+    // Account for reading and writing the storage version and the total number of commodities.
+    let mut reads = 2;
+    let mut writes = 2;
+    for (commodity_id, _) in Commodities::<T, I>::iter() {
+        CommoditiesInCollection::<T, I>::insert(default_collection, commodity_id, ());
+        reads += 1;
+        writes += 1;
+    }
+    for (account, total) in TotalForAccount::<T, I>::iter() {
+        TotalInCollectionForAccount::<T, I>::insert(default_collection, account, total);
+        reads += 1;
+        writes += 1;
+    }
+    TotalInCollection::<T, I>::insert(default_collection, Module::<T, I>::total());
+
+    StorageVersion::<I>::put(Releases::V3_0_0);
+
+    T::DbWeight::get().reads_writes(reads, writes)
+}
@@ -1,14 +1,21 @@
 // Creating mock runtime here
 
-use crate::{Module, Trait};
-use frame_support::{impl_outer_origin, parameter_types, weights::Weight};
+use crate::{
+    AttributePolicy, CommodityId, HashOfInfo, Instance1, Module, OnCommodityReceived, Sequential,
+    Trait,
+};
+use frame_support::{
+    dispatch::DispatchResult, impl_outer_origin, parameter_types, traits::Get, weights::Weight,
+    Hashable,
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
-    testing::Header,
+    testing::{Header, TestSignature, UintAuthorityId},
     traits::{BlakeTwo256, IdentityLookup},
     Perbill,
 };
+use std::cell::RefCell;
 
 impl_outer_origin! {
     pub enum Origin for Test where system = frame_system {}
@@ -43,15 +50,111 @@ impl system::Trait for Test {
     type AvailableBlockRatio = AvailableBlockRatio;
     type Version = ();
     type PalletInfo = ();
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
 }
 
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Trait for Test {
+    type Balance = u64;
+    type DustRemoval = ();
+    type Event = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = system::Module<Test>;
+    type MaxLocks = ();
+    type WeightInfo = ();
+}
+
 parameter_types! {
     pub const MaxCommodities: u128 = 5;
     pub const MaxCommoditiesPerUser: u64 = 2;
+    pub const KeyLimit: u32 = 8;
+    pub const ValueLimit: u32 = 16;
+    pub const MaxAttributes: u32 = 2;
+    pub const OwnerOrAdmin: AttributePolicy = AttributePolicy::OwnerOrAdmin;
+    pub const OwnerOnly: AttributePolicy = AttributePolicy::Owner;
+    pub const MaxAuctionsEndingPerBlock: u32 = 2;
+    pub const MaxOffersExpiringPerBlock: u32 = 2;
+    pub const MetadataLimit: u32 = 16;
+    pub const CollectionDeposit: u64 = 10;
+    pub const UnsignedPriority: u64 = 100;
+}
+
+thread_local! {
+    static TRANSFERABLE: RefCell<bool> = const { RefCell::new(true) };
+    static DEPOSIT_BASE: RefCell<u64> = const { RefCell::new(0) };
+    static DEPOSIT_PER_BYTE: RefCell<u64> = const { RefCell::new(0) };
+}
+
+// Whether the commodities of the default instance are transferable, which may be changed by tests.
+pub struct Transferable;
+impl Get<bool> for Transferable {
+    fn get() -> bool {
+        TRANSFERABLE.with(|transferable| *transferable.borrow())
+    }
+}
+
+pub fn set_transferable(transferable: bool) {
+    TRANSFERABLE.with(|value| *value.borrow_mut() = transferable);
+}
+
+// The storage deposits of both instances, which are free unless they are changed by tests.
+pub struct DepositBase;
+impl Get<u64> for DepositBase {
+    fn get() -> u64 {
+        DEPOSIT_BASE.with(|deposit| *deposit.borrow())
+    }
+}
+
+pub struct DepositPerByte;
+impl Get<u64> for DepositPerByte {
+    fn get() -> u64 {
+        DEPOSIT_PER_BYTE.with(|deposit| *deposit.borrow())
+    }
+}
+
+pub fn set_deposits(base: u64, per_byte: u64) {
+    DEPOSIT_BASE.with(|value| *value.borrow_mut() = base);
+    DEPOSIT_PER_BYTE.with(|value| *value.borrow_mut() = per_byte);
+}
+
+// The ID of a commodity of the default instance in the default collection.
+pub fn default_commodity_id(commodity_info: Vec<u8>) -> H256 {
+    (*b"default", commodity_info).blake2_256().into()
+}
+
+// The ID of a commodity of the default instance in a collection other than the default collection.
+pub fn collection_commodity_id(collection_id: u32, commodity_info: Vec<u8>) -> H256 {
+    (*b"collection", collection_id, commodity_info)
+        .blake2_256()
+        .into()
+}
+
+// An account that rejects all commodities.
+pub const REJECTING_ACCOUNT: u64 = 99;
+// An account that only accepts commodities that are sent with `ACCEPT_DATA`.
+pub const PICKY_ACCOUNT: u64 = 98;
+pub const ACCEPT_DATA: &[u8] = b"accept";
+
+pub struct TestReceiver;
+impl OnCommodityReceived<u64, CommodityId<Test>> for TestReceiver {
+    fn on_commodity_received(
+        _from_account: Option<&u64>,
+        dest_account: &u64,
+        _commodity_id: &CommodityId<Test>,
+        data: &[u8],
+    ) -> DispatchResult {
+        match *dest_account {
+            REJECTING_ACCOUNT => Err("Receiver rejects all commodities".into()),
+            PICKY_ACCOUNT if data != ACCEPT_DATA => Err("Receiver rejects this data".into()),
+            _ => Ok(()),
+        }
+    }
 }
 
 // For testing the pallet, we construct most of a mock runtime. This means
@@ -66,16 +169,79 @@ impl Trait for Test {
     type CommodityInfo = Vec<u8>;
     type CommodityLimit = MaxCommodities;
     type UserCommodityLimit = MaxCommoditiesPerUser;
+    type CollectionId = u32;
+    type MetadataLimit = MetadataLimit;
+    type CollectionDeposit = CollectionDeposit;
+    type AssetIdGenerator = HashOfInfo<Self>;
+    type KeyLimit = KeyLimit;
+    type ValueLimit = ValueLimit;
+    type MaxAttributes = MaxAttributes;
+    type AttributePolicy = OwnerOrAdmin;
+    type Currency = Balances;
+    type MaxAuctionsEndingPerBlock = MaxAuctionsEndingPerBlock;
+    type MaxOffersExpiringPerBlock = MaxOffersExpiringPerBlock;
+    type SwapAssets = Module<Test, Instance1>;
+    type Transferable = Transferable;
+    type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type DepositBase = DepositBase;
+    type DepositPerByte = DepositPerByte;
+    type OnCommodityReceived = TestReceiver;
+    type VoucherSignature = TestSignature;
+    type VoucherSigner = UintAuthorityId;
+    type UnsignedPriority = UnsignedPriority;
+    type WeightInfo = ();
 }
 
+// A second instance of the pallet that identifies commodities by the order in which they are minted
+// and only allows owners to manage attributes. Commodities of both instances are swapped for
+// commodities of this instance.
+impl Trait<Instance1> for Test {
+    type Event = ();
+    type CommodityAdmin = frame_system::EnsureRoot<Self::AccountId>;
+    type CommodityInfo = Vec<u8>;
+    type CommodityLimit = MaxCommodities;
+    type UserCommodityLimit = MaxCommoditiesPerUser;
+    type CollectionId = u32;
+    type MetadataLimit = MetadataLimit;
+    type CollectionDeposit = CollectionDeposit;
+    type AssetIdGenerator = Sequential<Self>;
+    type KeyLimit = KeyLimit;
+    type ValueLimit = ValueLimit;
+    type MaxAttributes = MaxAttributes;
+    type AttributePolicy = OwnerOnly;
+    type Currency = Balances;
+    type MaxAuctionsEndingPerBlock = MaxAuctionsEndingPerBlock;
+    type MaxOffersExpiringPerBlock = MaxOffersExpiringPerBlock;
+    type SwapAssets = Module<Test, Instance1>;
+    type Transferable = Transferable;
+    type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type DepositBase = DepositBase;
+    type DepositPerByte = DepositPerByte;
+    type OnCommodityReceived = TestReceiver;
+    type VoucherSignature = TestSignature;
+    type VoucherSigner = UintAuthorityId;
+    type UnsignedPriority = UnsignedPriority;
+    type WeightInfo = ();
+}
+
+pub type System = frame_system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
+
 // system under test
 pub type SUT = Module<Test>;
+// system under test, with sequential commodity IDs
+pub type SequentialSUT = Module<Test, Instance1>;
 
 // This function basically just builds a genesis storage key/value store according to
 // our desired mockup.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    system::GenesisConfig::default()
+    let mut storage = system::GenesisConfig::default()
         .build_storage::<Test>()
-        .unwrap()
-        .into()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100), (3, 100)],
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    storage.into()
 }
@@ -7,19 +7,29 @@
 //!
 //! Unique assets have an owner, identified by an account ID, and are defined by a common set of
 //! attributes (the asset info type). An asset ID type distinguishes unique assets from one another.
-//! Assets may be created (minted), destroyed (burned) or transferred.
+//! Asset IDs may be derived from the asset info or assigned independently of it, for instance from
+//! a counter. Assets may be created (minted), destroyed (burned) or transferred. The owner of an
+//! asset may approve another account to transfer that asset on its behalf, or authorize an operator
+//! to manage all of its assets. Batches of assets may be minted, burned or transferred atomically.
+//! An asset may entitle an account, such as its creator, to a royalty when it is sold. An asset may
+//! be locked, for instance while it is used as collateral, so that it cannot be transferred or
+//! burned. Assets may be grouped into collections, each with its own admin, limits and metadata.
 //!
 //! This abstraction is implemented by [pallet_commodities::Module](../struct.Module.html).
 
+use codec::Encode;
 use frame_support::{
     dispatch::{result::Result, DispatchError, DispatchResult},
-    traits::Get,
+    storage::{with_transaction, TransactionOutcome},
+    traits::{Get, LockIdentifier},
 };
 use sp_std::vec::Vec;
 
 /// An interface over a set of unique assets.
-/// Assets with equivalent attributes (as defined by the AssetInfo type) **must** have an equal ID
-/// and assets with different IDs **must not** have equivalent attributes.
+/// Every asset **must** have a distinct ID. If asset IDs are derived from asset info, assets with
+/// equivalent attributes (as defined by the AssetInfo type) **must** have an equal ID and assets
+/// with different IDs **must not** have equivalent attributes. If asset IDs are assigned
+/// independently of asset info, multiple assets may have equivalent attributes.
 pub trait UniqueAssets<AccountId> {
     /// The type used to identify unique assets.
     type AssetId;
@@ -43,7 +53,7 @@ pub trait UniqueAssets<AccountId> {
 
     /// Use the provided asset info to create a new unique asset for the specified user.
     /// This method **must** return an error in the following cases:
-    /// - The asset, as identified by the asset info, already exists.
+    /// - An asset with the ID that would be assigned to the new asset already exists.
     /// - The specified owner account has already reached the user asset limit.
     /// - The total asset limit has already been reached.
     fn mint(
@@ -59,4 +69,156 @@ pub trait UniqueAssets<AccountId> {
     /// - The asset with the specified ID does not exist.
     /// - The destination account has already reached the user asset limit.
     fn transfer(dest_account: &AccountId, asset_id: &Self::AssetId) -> DispatchResult;
+
+    /// Create a batch of new unique assets, each for its specified owner, and return their IDs in
+    /// the order that they were provided. If any asset cannot be minted, no assets are minted.
+    /// This method **must** return an error in any of the cases described for `mint`.
+    fn mint_batch(
+        assets: Vec<(AccountId, Self::AssetInfo)>,
+    ) -> Result<Vec<Self::AssetId>, DispatchError> {
+        with_transaction(|| {
+            let mut asset_ids = Vec::with_capacity(assets.len());
+            for (owner_account, asset_info) in assets {
+                match Self::mint(&owner_account, asset_info) {
+                    Ok(asset_id) => asset_ids.push(asset_id),
+                    Err(err) => return TransactionOutcome::Rollback(Err(err)),
+                }
+            }
+
+            TransactionOutcome::Commit(Ok(asset_ids))
+        })
+    }
+    /// Destroy a batch of assets. If any asset cannot be burned, no assets are burned.
+    /// This method **must** return an error in any of the cases described for `burn`.
+    fn burn_batch(asset_ids: &[Self::AssetId]) -> DispatchResult {
+        with_transaction(|| match asset_ids.iter().try_for_each(Self::burn) {
+            Ok(()) => TransactionOutcome::Commit(Ok(())),
+            Err(err) => TransactionOutcome::Rollback(Err(err)),
+        })
+    }
+    /// Transfer ownership of a batch of assets to another account. If any asset cannot be
+    /// transferred, no assets are transferred.
+    /// This method **must** return an error in any of the cases described for `transfer`.
+    fn transfer_batch(dest_account: &AccountId, asset_ids: &[Self::AssetId]) -> DispatchResult {
+        with_transaction(|| {
+            match asset_ids
+                .iter()
+                .try_for_each(|asset_id| Self::transfer(dest_account, asset_id))
+            {
+                Ok(()) => TransactionOutcome::Commit(Ok(())),
+                Err(err) => TransactionOutcome::Rollback(Err(err)),
+            }
+        })
+    }
+}
+
+/// A means of assigning an ID to a new unique asset.
+pub trait GenerateAssetId<AssetInfo, AssetId> {
+    /// Generate the ID for a new asset. `index` is the number of assets of this type that were
+    /// minted before the new asset, which is distinct for every asset.
+    fn generate_asset_id(index: u128, asset_info: &AssetInfo) -> AssetId;
+    /// Generate the ID for a new asset in a collection other than the default collection. The ID
+    /// must be distinct from the IDs of assets in every other collection, so generators that
+    /// derive IDs from asset info should also derive them from the collection ID. By default, the
+    /// ID is generated as if the asset belonged to the default collection.
+    fn generate_asset_id_in<CollectionId: Encode>(
+        _collection_id: &CollectionId,
+        index: u128,
+        asset_info: &AssetInfo,
+    ) -> AssetId {
+        Self::generate_asset_id(index, asset_info)
+    }
+}
+
+/// An interface over a set of unique assets that may be managed on behalf of their owners.
+/// Approvals are per-asset and **must** be cleared when an asset is transferred or burned.
+/// Operators are per-owner and may manage all of the owner's assets until they are revoked.
+pub trait ApprovableUniqueAssets<AccountId>: UniqueAssets<AccountId> {
+    /// The ID of the account that is approved to transfer an asset, if any.
+    fn approved_for(asset_id: &Self::AssetId) -> Option<AccountId>;
+
+    /// Approve an account to transfer an asset on behalf of its owner, replacing any existing
+    /// approval for that asset.
+    /// This method **must** return an error in the following case:
+    /// - The asset with the specified ID does not exist.
+    fn approve(approved_account: &AccountId, asset_id: &Self::AssetId) -> DispatchResult;
+    /// Remove the approval for an asset, if one exists.
+    /// This method **must** return an error in the following case:
+    /// - The asset with the specified ID does not exist.
+    fn clear_approval(asset_id: &Self::AssetId) -> DispatchResult;
+
+    /// Whether an account is authorized to manage all of the assets owned by another account.
+    fn is_operator(owner_account: &AccountId, operator_account: &AccountId) -> bool;
+    /// Authorize an account to manage all of the assets owned by another account.
+    fn set_operator(owner_account: &AccountId, operator_account: &AccountId);
+    /// Revoke an account's authorization to manage the assets owned by another account.
+    fn revoke_operator(owner_account: &AccountId, operator_account: &AccountId);
+}
+
+/// An interface over a set of unique assets that entitle an account to a royalty when they are
+/// sold.
+pub trait RoyaltyBearingUniqueAssets<AccountId, Balance>: UniqueAssets<AccountId> {
+    /// The account that is entitled to a royalty for the sale of an asset at the given price and
+    /// the amount of that royalty, if any.
+    fn royalty_info(asset_id: &Self::AssetId, sale_price: Balance) -> Option<(AccountId, Balance)>;
+}
+
+/// An interface over a set of unique assets that may be locked in place. Locks are named, like
+/// the locks of a `LockableCurrency`, so that independent modules may lock the same asset without
+/// interfering with one another. While any lock is held, `transfer` and `burn` **must** fail.
+pub trait LockableUniqueAssets<AccountId>: UniqueAssets<AccountId> {
+    /// Whether any lock is held on an asset.
+    fn is_locked(asset_id: &Self::AssetId) -> bool;
+    /// Lock an asset with the named lock. Locking an asset with a lock that is already held has no
+    /// further effect.
+    /// This method **must** return an error in the following case:
+    /// - The asset with the specified ID does not exist.
+    fn lock(asset_id: &Self::AssetId, lock_id: LockIdentifier) -> DispatchResult;
+    /// Release the named lock on an asset, if it is held. The asset remains locked while any other
+    /// lock is held.
+    fn unlock(asset_id: &Self::AssetId, lock_id: LockIdentifier);
+}
+
+/// An interface over a set of unique assets that are grouped into collections. Every asset belongs
+/// to exactly one collection, and each collection has its own admin, limits and metadata. The
+/// limits of a collection replace the limits of the set for the assets in that collection.
+pub trait UniqueCollections<AccountId>: UniqueAssets<AccountId> {
+    /// The type used to identify collections.
+    type CollectionId;
+
+    /// The ID of the collection that an asset belongs to.
+    fn collection_of(asset_id: &Self::AssetId) -> Self::CollectionId;
+    /// The total number of assets in a collection.
+    fn total_in_collection(collection_id: &Self::CollectionId) -> u128;
+    /// The total number of assets in a collection that are owned by an account.
+    fn total_in_collection_for_account(
+        collection_id: &Self::CollectionId,
+        account: &AccountId,
+    ) -> u64;
+
+    /// Create a new collection that is owned and managed by an account and return its ID. The
+    /// implementation may require a deposit from the owner for as long as the collection exists.
+    /// This method **must** return an error in the following cases:
+    /// - The metadata is larger than the implementation allows.
+    /// - The owner is unable to pay the deposit.
+    fn create_collection(
+        owner_account: &AccountId,
+        max_assets: u128,
+        max_assets_per_account: u64,
+        metadata: Vec<u8>,
+    ) -> Result<Self::CollectionId, DispatchError>;
+    /// Destroy a collection and release any deposit that was paid to create it.
+    /// This method **must** return an error in the following cases:
+    /// - The collection does not exist.
+    /// - The collection contains any assets.
+    fn destroy_collection(collection_id: &Self::CollectionId) -> DispatchResult;
+    /// Use the provided asset info to create a new unique asset in a collection for the specified
+    /// user. This method **must** return an error in the following cases:
+    /// - The collection does not exist.
+    /// - Any of the cases described for `mint`, with respect to the limits of the collection.
+    fn mint_into(
+        collection_id: &Self::CollectionId,
+        owner_account: &AccountId,
+        asset_info: Self::AssetInfo,
+    ) -> Result<Self::AssetId, DispatchError>;
 }
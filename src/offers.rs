@@ -0,0 +1,136 @@
+//! Offers to buy commodities.
+//!
+//! Any account other than the owner of a commodity may offer to buy it, whether or not it is
+//! listed for sale. The amount of an offer is reserved until the offer is withdrawn, accepted or
+//! expires, and an account may have at most one offer for each commodity. The owner of a
+//! commodity may accept any offer for it, in which case the amount of the offer is paid to the
+//! owner and ownership of the commodity is transferred to the account that made the offer. Offers
+//! are removed and their amounts are released at the start of their expiry block.
+
+use super::*;
+use frame_support::traits::ReservableCurrency;
+
+/// An offer to buy a commodity.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Offer<Balance, BlockNumber> {
+    /// The amount that will be paid for the commodity, which is reserved.
+    pub amount: Balance,
+    /// The block at the start of which the offer expires.
+    pub expiry: BlockNumber,
+}
+
+/// An offer to buy a commodity of this pallet.
+pub type OfferOf<T, I> = Offer<BalanceOf<T, I>, <T as frame_system::Trait>::BlockNumber>;
+
+impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// Make an offer for an existing commodity, reserving its amount and replacing any previous
+    /// offer that was made by the bidder.
+    pub(crate) fn place_offer(
+        bidder: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+        amount: BalanceOf<T, I>,
+        expiry: T::BlockNumber,
+    ) -> DispatchResult {
+        ensure!(
+            AccountForCommodity::<T, I>::contains_key(commodity_id),
+            Error::<T, I>::NonexistentCommodity
+        );
+        ensure!(
+            Self::account_for_commodity(commodity_id) != *bidder,
+            Error::<T, I>::OfferByOwner
+        );
+        ensure!(
+            expiry > frame_system::Module::<T>::block_number(),
+            Error::<T, I>::InvalidOfferExpiry
+        );
+
+        let previous = Self::offer(commodity_id, bidder);
+        let replaced = previous
+            .as_ref()
+            .map_or(0, |offer| (offer.expiry == expiry) as usize);
+        ensure!(
+            OffersExpiringAt::<T, I>::decode_len(expiry)
+                .unwrap_or(0)
+                .saturating_sub(replaced)
+                < T::MaxOffersExpiringPerBlock::get() as usize,
+            Error::<T, I>::TooManyOffersExpiring
+        );
+
+        // Reserve the new amount before releasing the previous one so that nothing has changed if
+        // the bidder is unable to reserve it.
+        T::Currency::reserve(bidder, amount)?;
+        if let Some(previous) = previous {
+            Self::remove_offer(commodity_id, bidder, previous);
+        }
+
+        OffersExpiringAt::<T, I>::append(expiry, (commodity_id, bidder));
+        Offers::<T, I>::insert(commodity_id, bidder, Offer { amount, expiry });
+        Ok(())
+    }
+
+    /// Withdraw an offer, releasing its amount.
+    pub(crate) fn withdraw(bidder: &T::AccountId, commodity_id: &CommodityId<T>) -> DispatchResult {
+        let offer = Self::offer(commodity_id, bidder).ok_or(Error::<T, I>::NoOffer)?;
+        Self::remove_offer(commodity_id, bidder, offer);
+        Ok(())
+    }
+
+    /// Sell a commodity to the account that made an offer for it, for the amount of the offer.
+    /// Returns the amount of the offer.
+    pub(crate) fn settle_offer(
+        seller: &T::AccountId,
+        bidder: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+    ) -> Result<BalanceOf<T, I>, dispatch::DispatchError> {
+        let offer = Self::offer(commodity_id, bidder).ok_or(Error::<T, I>::NoOffer)?;
+        Self::sell_reserved(seller, bidder, commodity_id, offer.amount)?;
+        Offers::<T, I>::remove(commodity_id, bidder);
+        Self::unschedule_offer_expiry(commodity_id, bidder, offer.expiry);
+        Ok(offer.amount)
+    }
+
+    /// Remove and release the amounts of all of the offers that expire at the specified block.
+    /// Returns the number of offers that expired.
+    pub(crate) fn prune_offers(now: T::BlockNumber) -> u32 {
+        let expiring = OffersExpiringAt::<T, I>::take(now);
+        for (commodity_id, bidder) in &expiring {
+            if let Some(offer) = Offers::<T, I>::take(commodity_id, bidder) {
+                T::Currency::unreserve(bidder, offer.amount);
+                Self::deposit_event(RawEvent::OfferExpired(*commodity_id, bidder.clone()));
+            }
+        }
+
+        expiring.len().saturated_into()
+    }
+
+    /// Release the reserved amount of an offer and pay it for the commodity. Either both happen
+    /// or neither does.
+    #[transactional]
+    fn sell_reserved(
+        seller: &T::AccountId,
+        bidder: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+        amount: BalanceOf<T, I>,
+    ) -> DispatchResult {
+        T::Currency::unreserve(bidder, amount);
+        Self::sell(seller, bidder, commodity_id, amount)
+    }
+
+    /// Remove an offer and release its amount.
+    fn remove_offer(commodity_id: &CommodityId<T>, bidder: &T::AccountId, offer: OfferOf<T, I>) {
+        T::Currency::unreserve(bidder, offer.amount);
+        Offers::<T, I>::remove(commodity_id, bidder);
+        Self::unschedule_offer_expiry(commodity_id, bidder, offer.expiry);
+    }
+
+    /// Remove an offer from the index of offers that expire at its expiry block.
+    fn unschedule_offer_expiry(
+        commodity_id: &CommodityId<T>,
+        bidder: &T::AccountId,
+        expiry: T::BlockNumber,
+    ) {
+        OffersExpiringAt::<T, I>::mutate(expiry, |offers| {
+            offers.retain(|(id, account)| id != commodity_id || account != bidder)
+        });
+    }
+}
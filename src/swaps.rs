@@ -0,0 +1,55 @@
+//! Swaps of commodities for other unique assets.
+//!
+//! The owner of a commodity may propose to swap it for another unique asset from the configured
+//! set of `SwapAssets`, which may be this pallet instance or any other implementation of
+//! `UniqueAssets`, optionally adding an amount of currency to the trade. The owner of the other
+//! asset may accept the proposal, in which case the commodity and the currency are transferred to
+//! it and the other asset is transferred to the proposer. An operator for the owner may propose a
+//! swap, but only the owner may add currency, which is paid from the owner's balance. Either all of
+//! the transfers happen or none do. A proposal is removed when its commodity is transferred or
+//! burned.
+
+use super::*;
+
+/// A proposal to swap a commodity for another unique asset.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Swap<AccountId, AssetId, Balance> {
+    /// The account that owns the commodity and that will receive the other asset.
+    pub proposer: AccountId,
+    /// The ID of the asset that the proposer wants in exchange for the commodity.
+    pub wanted: AssetId,
+    /// The amount of currency that the proposer will pay in addition to the commodity, which may
+    /// be zero.
+    pub payment: Balance,
+}
+
+/// A proposal to swap a commodity of this pallet for one of its swap assets.
+pub type SwapOf<T, I> =
+    Swap<<T as frame_system::Trait>::AccountId, CommodityId<T>, BalanceOf<T, I>>;
+
+impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// Exchange a commodity and the payment of a swap for the wanted asset. Either all of the
+    /// transfers happen or none do.
+    #[transactional]
+    pub(crate) fn exchange(
+        acceptor: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+        swap: &SwapOf<T, I>,
+    ) -> DispatchResult {
+        let payment = if swap.payment.is_zero() {
+            Ok(())
+        } else {
+            T::Currency::transfer(
+                &swap.proposer,
+                acceptor,
+                swap.payment,
+                ExistenceRequirement::KeepAlive,
+            )
+        };
+
+        // The body of a transactional function may not use the `?` operator.
+        payment
+            .and_then(|_| <Self as UniqueAssets<_>>::transfer(acceptor, commodity_id))
+            .and_then(|_| T::SwapAssets::transfer(&swap.proposer, &swap.wanted))
+    }
+}
@@ -1,10 +1,19 @@
 // Tests to be written here
 
 use crate::mock::*;
-use crate::nft::UniqueAssets;
+use crate::nft::{
+    ApprovableUniqueAssets, LockableUniqueAssets, RoyaltyBearingUniqueAssets, UniqueAssets,
+    UniqueCollections,
+};
 use crate::*;
-use frame_support::{assert_err, assert_ok, Hashable};
+use codec::Encode;
+use frame_support::{
+    assert_err, assert_ok,
+    traits::{OnFinalize, OnInitialize, OnRuntimeUpgrade},
+    Blake2_128Concat, StorageHasher,
+};
 use sp_core::H256;
+use sp_runtime::testing::TestSignature;
 
 #[test]
 fn mint() {
@@ -14,7 +23,7 @@ fn mint() {
         assert_eq!(<SUT as UniqueAssets<_>>::total(), 0);
         assert_eq!(<SUT as UniqueAssets<_>>::total_for_account(&1), 0);
         assert_eq!(
-            SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
             0
         );
 
@@ -26,15 +35,15 @@ fn mint() {
         assert_eq!(<SUT as UniqueAssets<_>>::burned(), 0);
         assert_eq!(SUT::total_for_account(1), 1);
         assert_eq!(<SUT as UniqueAssets<_>>::total_for_account(&1), 1);
-        let commodities_for_account = SUT::commodities_for_account::<u64>(1);
+        let commodities_for_account = <SUT as UniqueAssets<_>>::assets_for_account(&1);
         assert_eq!(commodities_for_account.len(), 1);
         assert_eq!(
             commodities_for_account[0].0,
-            Vec::<u8>::default().blake2_256().into()
+            default_commodity_id(Vec::<u8>::default())
         );
         assert_eq!(commodities_for_account[0].1, Vec::<u8>::default());
         assert_eq!(
-            SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
             1
         );
     });
@@ -97,15 +106,15 @@ fn burn() {
         assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
         assert_ok!(SUT::burn(
             Origin::signed(1),
-            Vec::<u8>::default().blake2_256().into()
+            default_commodity_id(Vec::<u8>::default())
         ));
 
         assert_eq!(SUT::total(), 0);
         assert_eq!(SUT::burned(), 1);
         assert_eq!(SUT::total_for_account(1), 0);
-        assert_eq!(SUT::commodities_for_account::<u64>(1), vec![]);
+        assert_eq!(<SUT as UniqueAssets<_>>::assets_for_account(&1), vec![]);
         assert_eq!(
-            SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
             0
         );
     });
@@ -117,7 +126,10 @@ fn burn_err_not_owner() {
         assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
 
         assert_err!(
-            SUT::burn(Origin::signed(2), Vec::<u8>::default().blake2_256().into()),
+            SUT::burn(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default())
+            ),
             Error::<Test, DefaultInstance>::NotCommodityOwner
         );
     });
@@ -127,7 +139,10 @@ fn burn_err_not_owner() {
 fn burn_err_not_exist() {
     new_test_ext().execute_with(|| {
         assert_err!(
-            SUT::burn(Origin::signed(1), Vec::<u8>::default().blake2_256().into()),
+            SUT::burn(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default())
+            ),
             Error::<Test, DefaultInstance>::NotCommodityOwner
         );
     });
@@ -140,23 +155,23 @@ fn transfer() {
         assert_ok!(SUT::transfer(
             Origin::signed(1),
             2,
-            Vec::<u8>::default().blake2_256().into()
+            default_commodity_id(Vec::<u8>::default())
         ));
 
         assert_eq!(SUT::total(), 1);
         assert_eq!(SUT::burned(), 0);
         assert_eq!(SUT::total_for_account(1), 0);
         assert_eq!(SUT::total_for_account(2), 1);
-        assert_eq!(SUT::commodities_for_account::<u64>(1), vec![]);
-        let commodities_for_account = SUT::commodities_for_account::<u64>(2);
+        assert_eq!(<SUT as UniqueAssets<_>>::assets_for_account(&1), vec![]);
+        let commodities_for_account = <SUT as UniqueAssets<_>>::assets_for_account(&2);
         assert_eq!(commodities_for_account.len(), 1);
         assert_eq!(
             commodities_for_account[0].0,
-            Vec::<u8>::default().blake2_256().into()
+            default_commodity_id(Vec::<u8>::default())
         );
         assert_eq!(commodities_for_account[0].1, Vec::<u8>::default());
         assert_eq!(
-            SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
             2
         );
     });
@@ -171,7 +186,7 @@ fn transfer_err_not_owner() {
             SUT::transfer(
                 Origin::signed(0),
                 2,
-                Vec::<u8>::default().blake2_256().into()
+                default_commodity_id(Vec::<u8>::default())
             ),
             Error::<Test, DefaultInstance>::NotCommodityOwner
         );
@@ -185,7 +200,7 @@ fn transfer_err_not_exist() {
             SUT::transfer(
                 Origin::signed(1),
                 2,
-                Vec::<u8>::default().blake2_256().into()
+                default_commodity_id(Vec::<u8>::default())
             ),
             Error::<Test, DefaultInstance>::NotCommodityOwner
         );
@@ -199,7 +214,7 @@ fn transfer_err_max_user() {
         assert_ok!(SUT::mint(Origin::root(), 1, vec![1]));
         assert_ok!(SUT::mint(Origin::root(), 2, Vec::<u8>::default()));
         assert_eq!(
-            SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
             2
         );
 
@@ -207,9 +222,3801 @@ fn transfer_err_max_user() {
             SUT::transfer(
                 Origin::signed(2),
                 1,
-                Vec::<u8>::default().blake2_256().into()
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+    });
+}
+
+#[test]
+fn approve() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(
+            SUT::approved_for::<H256>(default_commodity_id(Vec::<u8>::default())),
+            Some(2)
+        );
+        assert_eq!(
+            <SUT as ApprovableUniqueAssets<_>>::approved_for(&default_commodity_id(
+                Vec::<u8>::default()
+            )),
+            Some(2)
+        );
+    });
+}
+
+#[test]
+fn approve_err_not_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::approve(
+                Origin::signed(2),
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn clear_approval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_ok!(SUT::clear_approval(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(
+            SUT::approved_for::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+    });
+}
+
+#[test]
+fn transfer_from() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_ok!(SUT::transfer_from(
+            Origin::signed(2),
+            3,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(SUT::total_for_account(3), 1);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            3
+        );
+        assert_eq!(
+            SUT::approved_for::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+    });
+}
+
+#[test]
+fn transfer_from_err_not_approved() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_err!(
+            SUT::transfer_from(
+                Origin::signed(3),
+                3,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn transfer_clears_approval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            3,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(
+            SUT::approved_for::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_err!(
+            SUT::transfer_from(
+                Origin::signed(2),
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn burn_clears_approval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_ok!(SUT::burn(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(
+            SUT::approved_for::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+    });
+}
+
+#[test]
+fn mint_err_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::mint(Origin::root(), REJECTING_ACCOUNT, Vec::<u8>::default()),
+            Error::<Test, DefaultInstance>::CommodityRejected
+        );
+
+        assert_eq!(SUT::total(), 0);
+        assert_eq!(SUT::total_for_account(REJECTING_ACCOUNT), 0);
+    });
+}
+
+#[test]
+fn transfer_err_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(1),
+                REJECTING_ACCOUNT,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::CommodityRejected
+        );
+
+        assert_eq!(SUT::total_for_account(1), 1);
+        assert_eq!(SUT::total_for_account(REJECTING_ACCOUNT), 0);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            1
+        );
+    });
+}
+
+#[test]
+fn safe_transfer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::safe_transfer(
+            Origin::signed(1),
+            PICKY_ACCOUNT,
+            default_commodity_id(Vec::<u8>::default()),
+            ACCEPT_DATA.to_vec()
+        ));
+
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(SUT::total_for_account(PICKY_ACCOUNT), 1);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            PICKY_ACCOUNT
+        );
+    });
+}
+
+#[test]
+fn safe_transfer_by_approved() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::approve(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_ok!(SUT::safe_transfer(
+            Origin::signed(2),
+            3,
+            default_commodity_id(Vec::<u8>::default()),
+            Vec::new()
+        ));
+
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            3
+        );
+    });
+}
+
+#[test]
+fn safe_transfer_err_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::safe_transfer(
+                Origin::signed(1),
+                PICKY_ACCOUNT,
+                default_commodity_id(Vec::<u8>::default()),
+                b"reject".to_vec()
+            ),
+            Error::<Test, DefaultInstance>::CommodityRejected
+        );
+
+        assert_eq!(SUT::total_for_account(1), 1);
+        assert_eq!(SUT::total_for_account(PICKY_ACCOUNT), 0);
+    });
+}
+
+#[test]
+fn safe_transfer_err_not_approved() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::safe_transfer(
+                Origin::signed(2),
+                2,
+                default_commodity_id(Vec::<u8>::default()),
+                Vec::new()
+            ),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn set_operator() {
+    new_test_ext().execute_with(|| {
+        assert!(!SUT::is_operator(1, 2));
+        assert_ok!(SUT::set_operator(Origin::signed(1), 2));
+
+        assert!(SUT::is_operator(1, 2));
+        assert!(<SUT as ApprovableUniqueAssets<_>>::is_operator(&1, &2));
+        assert!(!SUT::is_operator(2, 1));
+    });
+}
+
+#[test]
+fn revoke_operator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::set_operator(Origin::signed(1), 2));
+        assert_ok!(SUT::revoke_operator(Origin::signed(1), 2));
+
+        assert!(!SUT::is_operator(1, 2));
+    });
+}
+
+#[test]
+fn transfer_by_operator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::set_operator(Origin::signed(1), 2));
+        assert_ok!(SUT::transfer(
+            Origin::signed(2),
+            3,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            3
+        );
+    });
+}
+
+#[test]
+fn burn_by_operator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::set_operator(Origin::signed(1), 2));
+        assert_ok!(SUT::burn(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(SUT::total(), 0);
+        assert_eq!(SUT::total_for_account(1), 0);
+    });
+}
+
+#[test]
+fn transfer_err_revoked_operator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::set_operator(Origin::signed(1), 2));
+        assert_ok!(SUT::revoke_operator(Origin::signed(1), 2));
+
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(2),
+                3,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn mint_batch() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_batch(
+            Origin::root(),
+            vec![(1, vec![]), (1, vec![0]), (2, vec![1])]
+        ));
+
+        assert_eq!(SUT::total(), 3);
+        assert_eq!(SUT::total_for_account(1), 2);
+        assert_eq!(SUT::total_for_account(2), 1);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(vec![1u8])),
+            2
+        );
+    });
+}
+
+#[test]
+fn mint_batch_err_rolls_back() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::mint_batch(
+                Origin::root(),
+                vec![(1, vec![]), (1, vec![0]), (1, vec![1])]
             ),
             Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
         );
+
+        assert_eq!(SUT::total(), 0);
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(<SUT as UniqueAssets<_>>::assets_for_account(&1), vec![]);
+    });
+}
+
+#[test]
+fn mint_batch_err_non_admin() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::mint_batch(Origin::signed(1), vec![(1, vec![])]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn burn_batch() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_batch(
+            Origin::root(),
+            vec![(1, vec![]), (1, vec![0]), (2, vec![1])]
+        ));
+        assert_ok!(SUT::burn_batch(
+            Origin::signed(1),
+            vec![
+                default_commodity_id(Vec::<u8>::default()),
+                default_commodity_id(vec![0u8])
+            ]
+        ));
+
+        assert_eq!(SUT::total(), 1);
+        assert_eq!(SUT::burned(), 2);
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(SUT::total_for_account(2), 1);
+    });
+}
+
+#[test]
+fn burn_batch_err_not_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_batch(
+            Origin::root(),
+            vec![(1, vec![]), (2, vec![0])]
+        ));
+
+        assert_err!(
+            SUT::burn_batch(
+                Origin::signed(1),
+                vec![
+                    default_commodity_id(Vec::<u8>::default()),
+                    default_commodity_id(vec![0u8])
+                ]
+            ),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+
+        assert_eq!(SUT::total(), 2);
+        assert_eq!(SUT::total_for_account(1), 1);
+    });
+}
+
+#[test]
+fn burn_batch_err_rolls_back() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::burn_batch(
+                Origin::signed(1),
+                vec![
+                    default_commodity_id(Vec::<u8>::default()),
+                    default_commodity_id(Vec::<u8>::default())
+                ]
+            ),
+            Error::<Test, DefaultInstance>::NonexistentCommodity
+        );
+
+        assert_eq!(SUT::total(), 1);
+        assert_eq!(SUT::burned(), 0);
+        assert_eq!(SUT::total_for_account(1), 1);
+    });
+}
+
+#[test]
+fn transfer_batch() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_batch(
+            Origin::root(),
+            vec![(1, vec![]), (1, vec![0])]
+        ));
+        assert_ok!(SUT::transfer_batch(
+            Origin::signed(1),
+            2,
+            vec![
+                default_commodity_id(Vec::<u8>::default()),
+                default_commodity_id(vec![0u8])
+            ]
+        ));
+
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(SUT::total_for_account(2), 2);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(vec![0u8])),
+            2
+        );
+    });
+}
+
+#[test]
+fn transfer_batch_err_rolls_back() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_batch(
+            Origin::root(),
+            vec![(1, vec![]), (1, vec![0]), (2, vec![1])]
+        ));
+
+        assert_err!(
+            SUT::transfer_batch(
+                Origin::signed(1),
+                2,
+                vec![
+                    default_commodity_id(Vec::<u8>::default()),
+                    default_commodity_id(vec![0u8])
+                ]
+            ),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+
+        assert_eq!(SUT::total_for_account(1), 2);
+        assert_eq!(SUT::total_for_account(2), 1);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            1
+        );
+    });
+}
+
+#[test]
+fn set_attribute() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::set_attribute(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            b"odometer".to_vec(),
+            b"1000".to_vec()
+        ));
+        assert_eq!(
+            SUT::attribute::<H256, _>(
+                default_commodity_id(Vec::<u8>::default()),
+                b"odometer".to_vec()
+            ),
+            Some(b"1000".to_vec())
+        );
+
+        assert_ok!(SUT::set_attribute(
+            Origin::root(),
+            default_commodity_id(Vec::<u8>::default()),
+            b"odometer".to_vec(),
+            b"2000".to_vec()
+        ));
+        assert_eq!(
+            SUT::attribute::<H256, _>(
+                default_commodity_id(Vec::<u8>::default()),
+                b"odometer".to_vec()
+            ),
+            Some(b"2000".to_vec())
+        );
+    });
+}
+
+#[test]
+fn set_attribute_err_not_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::set_attribute(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                b"odometer".to_vec(),
+                b"1000".to_vec()
+            ),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn set_attribute_err_nonexistent() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::set_attribute(
+                Origin::root(),
+                default_commodity_id(Vec::<u8>::default()),
+                b"odometer".to_vec(),
+                b"1000".to_vec()
+            ),
+            Error::<Test, DefaultInstance>::NonexistentCommodity
+        );
+    });
+}
+
+#[test]
+fn set_attribute_err_too_long() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::set_attribute(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default()),
+                vec![0; 9],
+                b"1000".to_vec()
+            ),
+            Error::<Test, DefaultInstance>::KeyTooLong
+        );
+        assert_err!(
+            SUT::set_attribute(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default()),
+                b"odometer".to_vec(),
+                vec![0; 17]
+            ),
+            Error::<Test, DefaultInstance>::ValueTooLong
+        );
+    });
+}
+
+#[test]
+fn set_attribute_err_too_many() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        let commodity_id: H256 = default_commodity_id(Vec::<u8>::default());
+        assert_ok!(SUT::set_attribute(
+            Origin::signed(1),
+            commodity_id,
+            b"odometer".to_vec(),
+            b"1000".to_vec()
+        ));
+        assert_ok!(SUT::set_attribute(
+            Origin::signed(1),
+            commodity_id,
+            b"color".to_vec(),
+            b"red".to_vec()
+        ));
+        assert_eq!(SUT::attribute_count(commodity_id), 2);
+
+        assert_err!(
+            SUT::set_attribute(
+                Origin::signed(1),
+                commodity_id,
+                b"owners".to_vec(),
+                b"1".to_vec()
+            ),
+            Error::<Test, DefaultInstance>::TooManyAttributes
+        );
+
+        // Existing attributes may still be changed.
+        assert_ok!(SUT::set_attribute(
+            Origin::signed(1),
+            commodity_id,
+            b"odometer".to_vec(),
+            b"2000".to_vec()
+        ));
+        assert_eq!(SUT::attribute_count(commodity_id), 2);
+
+        assert_ok!(SUT::clear_attribute(
+            Origin::signed(1),
+            commodity_id,
+            b"color".to_vec()
+        ));
+        assert_ok!(SUT::clear_attribute(
+            Origin::signed(1),
+            commodity_id,
+            b"color".to_vec()
+        ));
+        assert_eq!(SUT::attribute_count(commodity_id), 1);
+        assert_ok!(SUT::set_attribute(
+            Origin::signed(1),
+            commodity_id,
+            b"owners".to_vec(),
+            b"1".to_vec()
+        ));
+
+        assert_ok!(SUT::burn(Origin::signed(1), commodity_id));
+        assert!(!AttributeCount::<Test, DefaultInstance>::contains_key(
+            commodity_id
+        ));
+    });
+}
+
+#[test]
+fn set_attribute_owner_policy() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SequentialSUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SequentialSUT::set_attribute(
+                Origin::root(),
+                H256::from_low_u64_be(0),
+                b"odometer".to_vec(),
+                b"1000".to_vec()
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(SequentialSUT::set_attribute(
+            Origin::signed(1),
+            H256::from_low_u64_be(0),
+            b"odometer".to_vec(),
+            b"1000".to_vec()
+        ));
+    });
+}
+
+#[test]
+fn clear_attribute() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::set_attribute(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            b"odometer".to_vec(),
+            b"1000".to_vec()
+        ));
+        assert_ok!(SUT::clear_attribute(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            b"odometer".to_vec()
+        ));
+
+        assert_eq!(
+            SUT::attribute::<H256, _>(
+                default_commodity_id(Vec::<u8>::default()),
+                b"odometer".to_vec()
+            ),
+            None
+        );
+    });
+}
+
+#[test]
+fn burn_clears_attributes() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::set_attribute(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            b"odometer".to_vec(),
+            b"1000".to_vec()
+        ));
+        assert_ok!(SUT::burn(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(
+            SUT::attribute::<H256, _>(
+                default_commodity_id(Vec::<u8>::default()),
+                b"odometer".to_vec()
+            ),
+            None
+        );
+    });
+}
+
+#[test]
+fn mint_with_royalty() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_with_royalty(
+            Origin::root(),
+            1,
+            Vec::<u8>::default(),
+            3,
+            Perbill::from_percent(10)
+        ));
+
+        assert_eq!(SUT::total_for_account(1), 1);
+        assert_eq!(
+            SUT::royalty::<H256>(default_commodity_id(Vec::<u8>::default())),
+            Some((3, Perbill::from_percent(10)))
+        );
+        assert_eq!(
+            <SUT as RoyaltyBearingUniqueAssets<_, _>>::royalty_info(
+                &default_commodity_id(Vec::<u8>::default()),
+                50
+            ),
+            Some((3, 5))
+        );
+    });
+}
+
+#[test]
+fn mint_with_royalty_err_non_admin() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::mint_with_royalty(
+                Origin::signed(1),
+                1,
+                Vec::<u8>::default(),
+                3,
+                Perbill::from_percent(10)
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn royalty_info_none() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_eq!(
+            <SUT as RoyaltyBearingUniqueAssets<_, _>>::royalty_info(
+                &default_commodity_id(Vec::<u8>::default()),
+                50
+            ),
+            None
+        );
+    });
+}
+
+#[test]
+fn pay_for_commodity() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_with_royalty(
+            Origin::root(),
+            2,
+            Vec::<u8>::default(),
+            3,
+            Perbill::from_percent(10)
+        ));
+        assert_ok!(SUT::pay_for_commodity(
+            &1,
+            &2,
+            &default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+
+        assert_eq!(Balances::free_balance(1), 50);
+        assert_eq!(Balances::free_balance(2), 145);
+        assert_eq!(Balances::free_balance(3), 105);
+    });
+}
+
+#[test]
+fn pay_for_commodity_without_royalty() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 2, Vec::<u8>::default()));
+        assert_ok!(SUT::pay_for_commodity(
+            &1,
+            &2,
+            &default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+
+        assert_eq!(Balances::free_balance(1), 50);
+        assert_eq!(Balances::free_balance(2), 150);
+    });
+}
+
+#[test]
+fn pay_for_commodity_err_rolls_back() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_with_royalty(
+            Origin::root(),
+            2,
+            Vec::<u8>::default(),
+            3,
+            Perbill::from_percent(10)
+        ));
+        assert!(
+            SUT::pay_for_commodity(&1, &2, &default_commodity_id(Vec::<u8>::default()), 100)
+                .is_err()
+        );
+
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(2), 100);
+        assert_eq!(Balances::free_balance(3), 100);
+    });
+}
+
+#[test]
+fn burn_clears_royalty() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_with_royalty(
+            Origin::root(),
+            1,
+            Vec::<u8>::default(),
+            3,
+            Perbill::from_percent(10)
+        ));
+        assert_ok!(SUT::burn(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(
+            SUT::royalty::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+    });
+}
+
+#[test]
+fn list_for_sale() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::list_for_sale(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+
+        assert_eq!(
+            SUT::listing::<H256>(default_commodity_id(Vec::<u8>::default())),
+            Some(50)
+        );
+    });
+}
+
+#[test]
+fn list_for_sale_err_not_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::list_for_sale(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                50
+            ),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn cancel_listing() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::list_for_sale(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+        assert_ok!(SUT::cancel_listing(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(
+            SUT::listing::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_err!(
+            SUT::cancel_listing(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::NotForSale
+        );
+    });
+}
+
+#[test]
+fn buy() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_with_royalty(
+            Origin::root(),
+            1,
+            Vec::<u8>::default(),
+            3,
+            Perbill::from_percent(10)
+        ));
+        assert_ok!(SUT::list_for_sale(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+        assert_ok!(SUT::buy(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            60
+        ));
+
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(SUT::total_for_account(2), 1);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            2
+        );
+        assert_eq!(
+            SUT::listing::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_eq!(Balances::free_balance(1), 145);
+        assert_eq!(Balances::free_balance(2), 50);
+        assert_eq!(Balances::free_balance(3), 105);
+    });
+}
+
+#[test]
+fn buy_err_not_for_sale() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::buy(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                50
+            ),
+            Error::<Test, DefaultInstance>::NotForSale
+        );
+    });
+}
+
+#[test]
+fn buy_err_price_too_high() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::list_for_sale(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+        assert_err!(
+            SUT::buy(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                49
+            ),
+            Error::<Test, DefaultInstance>::PriceTooHigh
+        );
+    });
+}
+
+#[test]
+fn buy_err_rejected_rolls_back() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&REJECTING_ACCOUNT, 100);
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::list_for_sale(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+        assert_err!(
+            SUT::buy(
+                Origin::signed(REJECTING_ACCOUNT),
+                default_commodity_id(Vec::<u8>::default()),
+                50
+            ),
+            Error::<Test, DefaultInstance>::CommodityRejected
+        );
+
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            1
+        );
+        assert_eq!(
+            SUT::listing::<H256>(default_commodity_id(Vec::<u8>::default())),
+            Some(50)
+        );
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(REJECTING_ACCOUNT), 100);
+    });
+}
+
+#[test]
+fn transfer_removes_listing() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::list_for_sale(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(
+            SUT::listing::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+    });
+}
+
+#[test]
+fn burn_removes_listing() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::list_for_sale(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+        assert_ok!(SUT::burn(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_eq!(
+            SUT::listing::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+    });
+}
+
+#[test]
+fn start_auction() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::list_for_sale(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+        assert_ok!(SUT::start_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            10,
+            5
+        ));
+
+        assert_eq!(
+            SUT::auction::<H256>(default_commodity_id(Vec::<u8>::default())),
+            Some(Auction {
+                seller: 1,
+                reserve_price: 10,
+                end: 5,
+                highest_bid: None,
+            })
+        );
+        assert_eq!(
+            SUT::auctions_ending_at(5),
+            vec![default_commodity_id(Vec::<u8>::default())]
+        );
+        assert_eq!(
+            SUT::listing::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+    });
+}
+
+#[test]
+fn start_auction_err_invalid_end() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::start_auction(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default()),
+                10,
+                5
+            ),
+            Error::<Test, DefaultInstance>::InvalidAuctionEnd
+        );
+    });
+}
+
+#[test]
+fn start_auction_err_too_many_ending() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_batch(
+            Origin::root(),
+            vec![(1, vec![]), (1, vec![0]), (2, vec![1])]
+        ));
+        assert_ok!(SUT::start_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            10,
+            5
+        ));
+        assert_ok!(SUT::start_auction(
+            Origin::signed(1),
+            default_commodity_id(vec![0u8]),
+            10,
+            5
+        ));
+        assert_err!(
+            SUT::start_auction(Origin::signed(2), default_commodity_id(vec![1u8]), 10, 5),
+            Error::<Test, DefaultInstance>::TooManyAuctionsEnding
+        );
+    });
+}
+
+#[test]
+fn auction_in_progress_locks_commodity() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::start_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            10,
+            5
+        ));
+
+        assert_err!(
+            SUT::start_auction(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default()),
+                10,
+                6
+            ),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(1),
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+        assert_err!(
+            SUT::burn(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+        assert_err!(
+            SUT::list_for_sale(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default()),
+                50
+            ),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+    });
+}
+
+#[test]
+fn auction_blocks_every_transfer_and_burn() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = default_commodity_id(Vec::<u8>::default());
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::start_auction(Origin::signed(1), commodity_id, 10, 5));
+        assert_ok!(SUT::bid(Origin::signed(2), commodity_id, 20));
+
+        assert_err!(
+            SUT::recover(Origin::root(), 3, commodity_id),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+        assert_err!(
+            SUT::force_transfer(Origin::root(), 1, 3, commodity_id),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+        assert_err!(
+            SUT::force_burn(Origin::root(), commodity_id),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+        assert_err!(
+            <SUT as UniqueAssets<_>>::transfer(&3, &commodity_id),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+        assert_err!(
+            <SUT as UniqueAssets<_>>::burn(&commodity_id),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+
+        assert_eq!(SUT::account_for_commodity(commodity_id), 1);
+        assert_eq!(Balances::reserved_balance(2), 20);
+    });
+}
+
+#[test]
+fn auction_blocks_swap_for_auctioned_asset() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = default_commodity_id(Vec::<u8>::default());
+        let wanted = H256::from_low_u64_be(0);
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SequentialSUT::mint(Origin::root(), 2, Vec::<u8>::default()));
+        assert_ok!(SUT::propose_swap(
+            Origin::signed(1),
+            commodity_id,
+            wanted,
+            0
+        ));
+        assert_ok!(SequentialSUT::start_auction(
+            Origin::signed(2),
+            wanted,
+            10,
+            5
+        ));
+
+        assert_err!(
+            SUT::accept_swap(Origin::signed(2), commodity_id, 0),
+            Error::<Test, Instance1>::AuctionInProgress
+        );
+        assert_eq!(SUT::account_for_commodity(commodity_id), 1);
+        assert_eq!(SequentialSUT::account_for_commodity(wanted), 2);
+    });
+}
+
+#[test]
+fn bid() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::start_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            10,
+            5
+        ));
+        assert_ok!(SUT::bid(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            10
+        ));
+        assert_eq!(Balances::reserved_balance(2), 10);
+
+        assert_ok!(SUT::bid(
+            Origin::signed(3),
+            default_commodity_id(Vec::<u8>::default()),
+            20
+        ));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::reserved_balance(3), 20);
+        assert_eq!(
+            SUT::auction::<H256>(default_commodity_id(Vec::<u8>::default()))
+                .and_then(|auction| auction.highest_bid),
+            Some((3, 20))
+        );
+    });
+}
+
+#[test]
+fn bid_err_too_low() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::start_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            10,
+            5
+        ));
+        assert_err!(
+            SUT::bid(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                9
+            ),
+            Error::<Test, DefaultInstance>::BidTooLow
+        );
+        assert_ok!(SUT::bid(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            10
+        ));
+        assert_err!(
+            SUT::bid(
+                Origin::signed(3),
+                default_commodity_id(Vec::<u8>::default()),
+                10
+            ),
+            Error::<Test, DefaultInstance>::BidTooLow
+        );
+    });
+}
+
+#[test]
+fn bid_err_not_auctioned() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::bid(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                10
+            ),
+            Error::<Test, DefaultInstance>::NotAuctioned
+        );
+    });
+}
+
+#[test]
+fn bid_err_expired() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::start_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            10,
+            5
+        ));
+        System::set_block_number(6);
+        assert_err!(
+            SUT::bid(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                10
+            ),
+            Error::<Test, DefaultInstance>::AuctionExpired
+        );
+    });
+}
+
+#[test]
+fn settle_auction() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::start_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            10,
+            5
+        ));
+        assert_ok!(SUT::bid(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            10
+        ));
+        assert_ok!(SUT::bid(
+            Origin::signed(3),
+            default_commodity_id(Vec::<u8>::default()),
+            20
+        ));
+
+        SUT::on_finalize(4);
+        assert!(SUT::auction::<H256>(default_commodity_id(Vec::<u8>::default())).is_some());
+
+        SUT::on_finalize(5);
+        assert_eq!(
+            SUT::auction::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_eq!(SUT::auctions_ending_at(5), vec![]);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            3
+        );
+        assert_eq!(Balances::free_balance(1), 120);
+        assert_eq!(Balances::free_balance(2), 100);
+        assert_eq!(Balances::free_balance(3), 80);
+        assert_eq!(Balances::reserved_balance(3), 0);
+    });
+}
+
+#[test]
+fn settle_auction_without_bids() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::start_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            10,
+            5
+        ));
+
+        SUT::on_finalize(5);
+        assert_eq!(
+            SUT::auction::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+    });
+}
+
+#[test]
+fn settle_auction_refunds_if_sale_fails() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![1u8]));
+        assert_ok!(SUT::start_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            10,
+            5
+        ));
+        assert_ok!(SUT::bid(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            10
+        ));
+        // The bidder may not receive the commodity once it owns the maximum number of commodities.
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![2u8]));
+
+        SUT::on_finalize(5);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            1
+        );
+        assert_eq!(
+            SUT::auction::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(2), 100);
+        assert_eq!(Balances::reserved_balance(2), 0);
+    });
+}
+
+#[test]
+fn dutch_auction_price_at() {
+    let auction = DutchAuction {
+        seller: 1,
+        start_price: 100u64,
+        floor_price: 20,
+        start: 10u64,
+        end: 20,
+    };
+
+    assert_eq!(auction.price_at(10), 100);
+    assert_eq!(auction.price_at(15), 60);
+    assert_eq!(auction.price_at(19), 28);
+    assert_eq!(auction.price_at(20), 20);
+    assert_eq!(auction.price_at(25), 20);
+}
+
+#[test]
+fn start_dutch_auction() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::list_for_sale(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+        assert_ok!(SUT::start_dutch_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            100,
+            20,
+            10
+        ));
+
+        assert_eq!(
+            SUT::dutch_auction::<H256>(default_commodity_id(Vec::<u8>::default())),
+            Some(DutchAuction {
+                seller: 1,
+                start_price: 100,
+                floor_price: 20,
+                start: 0,
+                end: 10,
+            })
+        );
+        assert_eq!(
+            SUT::listing::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_eq!(
+            SUT::dutch_auction_price(&default_commodity_id(Vec::<u8>::default())),
+            Some(100)
+        );
+    });
+}
+
+#[test]
+fn start_dutch_auction_err_invalid_prices() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::start_dutch_auction(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default()),
+                20,
+                100,
+                10
+            ),
+            Error::<Test, DefaultInstance>::InvalidAuctionPrices
+        );
+    });
+}
+
+#[test]
+fn dutch_auction_locks_commodity() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::start_dutch_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            100,
+            20,
+            10
+        ));
+
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(1),
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+        assert_err!(
+            SUT::burn(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+        assert_err!(
+            SUT::start_auction(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default()),
+                10,
+                5
+            ),
+            Error::<Test, DefaultInstance>::AuctionInProgress
+        );
+        assert_err!(
+            SUT::bid(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                100
+            ),
+            Error::<Test, DefaultInstance>::NotAuctioned
+        );
+    });
+}
+
+#[test]
+fn buy_from_dutch_auction() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::start_dutch_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            100,
+            20,
+            10
+        ));
+
+        System::set_block_number(5);
+        assert_err!(
+            SUT::buy(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                59
+            ),
+            Error::<Test, DefaultInstance>::PriceTooHigh
+        );
+        assert_ok!(SUT::buy(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            60
+        ));
+
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            2
+        );
+        assert_eq!(Balances::free_balance(1), 160);
+        assert_eq!(Balances::free_balance(2), 40);
+        assert_eq!(
+            SUT::dutch_auction::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_eq!(SUT::auctions_ending_at(10), vec![]);
+        assert_err!(
+            SUT::buy(
+                Origin::signed(3),
+                default_commodity_id(Vec::<u8>::default()),
+                100
+            ),
+            Error::<Test, DefaultInstance>::NotForSale
+        );
+    });
+}
+
+#[test]
+fn close_dutch_auction() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::start_dutch_auction(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            100,
+            20,
+            10
+        ));
+
+        SUT::on_finalize(10);
+        assert_eq!(
+            SUT::dutch_auction::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+    });
+}
+
+#[test]
+fn make_offer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::make_offer(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            30,
+            5
+        ));
+
+        assert_eq!(
+            SUT::offer::<H256, u64>(default_commodity_id(Vec::<u8>::default()), 2),
+            Some(Offer {
+                amount: 30,
+                expiry: 5,
+            })
+        );
+        assert_eq!(
+            SUT::offers_expiring_at(5),
+            vec![(default_commodity_id(Vec::<u8>::default()), 2)]
+        );
+        assert_eq!(Balances::free_balance(2), 70);
+        assert_eq!(Balances::reserved_balance(2), 30);
+    });
+}
+
+#[test]
+fn make_offer_replaces_previous() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::make_offer(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            30,
+            5
+        ));
+        assert_ok!(SUT::make_offer(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            40,
+            6
+        ));
+
+        assert_eq!(
+            SUT::offer::<H256, u64>(default_commodity_id(Vec::<u8>::default()), 2),
+            Some(Offer {
+                amount: 40,
+                expiry: 6,
+            })
+        );
+        assert_eq!(SUT::offers_expiring_at(5), vec![]);
+        assert_eq!(
+            SUT::offers_expiring_at(6),
+            vec![(default_commodity_id(Vec::<u8>::default()), 2)]
+        );
+        assert_eq!(Balances::free_balance(2), 60);
+        assert_eq!(Balances::reserved_balance(2), 40);
+    });
+}
+
+#[test]
+fn make_offer_err_by_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::make_offer(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default()),
+                30,
+                5
+            ),
+            Error::<Test, DefaultInstance>::OfferByOwner
+        );
+    });
+}
+
+#[test]
+fn make_offer_err_nonexistent() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::make_offer(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                30,
+                5
+            ),
+            Error::<Test, DefaultInstance>::NonexistentCommodity
+        );
+    });
+}
+
+#[test]
+fn make_offer_err_invalid_expiry() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        System::set_block_number(5);
+        assert_err!(
+            SUT::make_offer(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                30,
+                5
+            ),
+            Error::<Test, DefaultInstance>::InvalidOfferExpiry
+        );
+    });
+}
+
+#[test]
+fn make_offer_err_too_many_expiring() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![1u8]));
+        assert_ok!(SUT::make_offer(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            30,
+            5
+        ));
+        assert_ok!(SUT::make_offer(
+            Origin::signed(3),
+            default_commodity_id(Vec::<u8>::default()),
+            30,
+            5
+        ));
+
+        assert_err!(
+            SUT::make_offer(Origin::signed(3), default_commodity_id(vec![1u8]), 30, 5),
+            Error::<Test, DefaultInstance>::TooManyOffersExpiring
+        );
+        // Replacing an offer does not count against the limit.
+        assert_ok!(SUT::make_offer(
+            Origin::signed(3),
+            default_commodity_id(Vec::<u8>::default()),
+            40,
+            5
+        ));
+        assert_eq!(Balances::reserved_balance(3), 40);
+    });
+}
+
+#[test]
+fn withdraw_offer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::make_offer(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            30,
+            5
+        ));
+
+        assert_ok!(SUT::withdraw_offer(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_eq!(
+            SUT::offer::<H256, u64>(default_commodity_id(Vec::<u8>::default()), 2),
+            None
+        );
+        assert_eq!(SUT::offers_expiring_at(5), vec![]);
+        assert_eq!(Balances::free_balance(2), 100);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_err!(
+            SUT::withdraw_offer(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::NoOffer
+        );
+    });
+}
+
+#[test]
+fn accept_offer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::list_for_sale(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+        assert_ok!(SUT::make_offer(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            30,
+            5
+        ));
+        assert_ok!(SUT::make_offer(
+            Origin::signed(3),
+            default_commodity_id(Vec::<u8>::default()),
+            20,
+            5
+        ));
+
+        assert_err!(
+            SUT::accept_offer(
+                Origin::signed(3),
+                default_commodity_id(Vec::<u8>::default()),
+                2
+            ),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+        assert_ok!(SUT::accept_offer(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            2
+        ));
+
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            2
+        );
+        assert_eq!(
+            SUT::listing::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_eq!(
+            SUT::offer::<H256, u64>(default_commodity_id(Vec::<u8>::default()), 2),
+            None
+        );
+        assert_eq!(
+            SUT::offers_expiring_at(5),
+            vec![(default_commodity_id(Vec::<u8>::default()), 3)]
+        );
+        assert_eq!(Balances::free_balance(1), 130);
+        assert_eq!(Balances::free_balance(2), 70);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::reserved_balance(3), 20);
+    });
+}
+
+#[test]
+fn accept_offer_err_no_offer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::accept_offer(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default()),
+                2
+            ),
+            Error::<Test, DefaultInstance>::NoOffer
+        );
+    });
+}
+
+#[test]
+fn accept_offer_failure_keeps_offer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![1u8]));
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![2u8]));
+        assert_ok!(SUT::make_offer(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            30,
+            5
+        ));
+
+        assert_err!(
+            SUT::accept_offer(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default()),
+                2
+            ),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            1
+        );
+        assert_eq!(
+            SUT::offer::<H256, u64>(default_commodity_id(Vec::<u8>::default()), 2),
+            Some(Offer {
+                amount: 30,
+                expiry: 5,
+            })
+        );
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::reserved_balance(2), 30);
+    });
+}
+
+#[test]
+fn prune_expired_offers() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::make_offer(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            30,
+            5
+        ));
+
+        SUT::on_initialize(4);
+        assert!(SUT::offer::<H256, u64>(default_commodity_id(Vec::<u8>::default()), 2).is_some());
+
+        SUT::on_initialize(5);
+        assert_eq!(
+            SUT::offer::<H256, u64>(default_commodity_id(Vec::<u8>::default()), 2),
+            None
+        );
+        assert_eq!(SUT::offers_expiring_at(5), vec![]);
+        assert_eq!(Balances::free_balance(2), 100);
+        assert_eq!(Balances::reserved_balance(2), 0);
+    });
+}
+
+#[test]
+fn propose_swap() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::propose_swap(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                H256::from_low_u64_be(0),
+                10
+            ),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+        assert_ok!(SUT::propose_swap(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            H256::from_low_u64_be(0),
+            10
+        ));
+        assert_eq!(
+            SUT::swap::<H256>(default_commodity_id(Vec::<u8>::default())),
+            Some(Swap {
+                proposer: 1,
+                wanted: H256::from_low_u64_be(0),
+                payment: 10,
+            })
+        );
+    });
+}
+
+#[test]
+fn cancel_swap() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::cancel_swap(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::NoSwap
+        );
+        assert_ok!(SUT::propose_swap(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            H256::from_low_u64_be(0),
+            10
+        ));
+
+        assert_ok!(SUT::cancel_swap(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_eq!(
+            SUT::swap::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+    });
+}
+
+#[test]
+fn swap_removed_on_transfer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::propose_swap(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            H256::from_low_u64_be(0),
+            10
+        ));
+
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            3,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_eq!(
+            SUT::swap::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+    });
+}
+
+#[test]
+fn propose_swap_by_operator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::set_operator(Origin::signed(1), 2));
+
+        // An operator may not pay for a swap with the owner's currency.
+        assert_err!(
+            SUT::propose_swap(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                H256::from_low_u64_be(0),
+                90
+            ),
+            Error::<Test, DefaultInstance>::PaymentByOperator
+        );
+        assert_ok!(SUT::propose_swap(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            H256::from_low_u64_be(0),
+            0
+        ));
+        assert_eq!(
+            SUT::swap::<H256>(default_commodity_id(Vec::<u8>::default())),
+            Some(Swap {
+                proposer: 1,
+                wanted: H256::from_low_u64_be(0),
+                payment: 0,
+            })
+        );
+    });
+}
+
+#[test]
+fn accept_swap() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SequentialSUT::mint(Origin::root(), 2, Vec::<u8>::default()));
+        assert_ok!(SUT::propose_swap(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            H256::from_low_u64_be(0),
+            10
+        ));
+
+        assert_err!(
+            SUT::accept_swap(
+                Origin::signed(3),
+                default_commodity_id(Vec::<u8>::default()),
+                10
+            ),
+            Error::<Test, DefaultInstance>::NotSwapCounterparty
+        );
+        assert_err!(
+            SUT::accept_swap(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                11
+            ),
+            Error::<Test, DefaultInstance>::SwapPaymentTooLow
+        );
+        assert_ok!(SUT::accept_swap(
+            Origin::signed(2),
+            default_commodity_id(Vec::<u8>::default()),
+            10
+        ));
+
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            2
+        );
+        assert_eq!(
+            SequentialSUT::account_for_commodity::<H256>(H256::from_low_u64_be(0)),
+            1
+        );
+        assert_eq!(
+            SUT::swap::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_eq!(Balances::free_balance(1), 90);
+        assert_eq!(Balances::free_balance(2), 110);
+    });
+}
+
+#[test]
+fn accept_swap_same_instance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SequentialSUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SequentialSUT::mint(Origin::root(), 2, Vec::<u8>::default()));
+        assert_ok!(SequentialSUT::propose_swap(
+            Origin::signed(1),
+            H256::from_low_u64_be(0),
+            H256::from_low_u64_be(1),
+            0
+        ));
+
+        assert_ok!(SequentialSUT::accept_swap(
+            Origin::signed(2),
+            H256::from_low_u64_be(0),
+            0
+        ));
+        assert_eq!(
+            SequentialSUT::account_for_commodity::<H256>(H256::from_low_u64_be(0)),
+            2
+        );
+        assert_eq!(
+            SequentialSUT::account_for_commodity::<H256>(H256::from_low_u64_be(1)),
+            1
+        );
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(2), 100);
+    });
+}
+
+#[test]
+fn accept_swap_failure_reverts() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![1u8]));
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![2u8]));
+        assert_ok!(SequentialSUT::mint(Origin::root(), 2, Vec::<u8>::default()));
+        assert_ok!(SUT::propose_swap(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            H256::from_low_u64_be(0),
+            10
+        ));
+
+        assert_err!(
+            SUT::accept_swap(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                10
+            ),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            1
+        );
+        assert_eq!(
+            SequentialSUT::account_for_commodity::<H256>(H256::from_low_u64_be(0)),
+            2
+        );
+        assert!(SUT::swap::<H256>(default_commodity_id(Vec::<u8>::default())).is_some());
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(2), 100);
+    });
+}
+
+#[test]
+fn lock() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(<SUT as LockableUniqueAssets<_>>::lock(
+            &default_commodity_id(Vec::<u8>::default()),
+            *b"dispute "
+        ));
+
+        assert!(<SUT as LockableUniqueAssets<_>>::is_locked(
+            &default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(1),
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::CommodityLocked
+        );
+        assert_err!(
+            SUT::burn(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::CommodityLocked
+        );
+        assert_err!(
+            <SUT as UniqueAssets<_>>::transfer(&2, &default_commodity_id(Vec::<u8>::default())),
+            Error::<Test, DefaultInstance>::CommodityLocked
+        );
+        assert_err!(
+            <SUT as UniqueAssets<_>>::burn(&default_commodity_id(Vec::<u8>::default())),
+            Error::<Test, DefaultInstance>::CommodityLocked
+        );
+    });
+}
+
+#[test]
+fn lock_err_nonexistent() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            <SUT as LockableUniqueAssets<_>>::lock(
+                &default_commodity_id(Vec::<u8>::default()),
+                *b"dispute "
+            ),
+            Error::<Test, DefaultInstance>::NonexistentCommodity
+        );
+    });
+}
+
+#[test]
+fn unlock() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(<SUT as LockableUniqueAssets<_>>::lock(
+            &default_commodity_id(Vec::<u8>::default()),
+            *b"dispute "
+        ));
+        assert_ok!(<SUT as LockableUniqueAssets<_>>::lock(
+            &default_commodity_id(Vec::<u8>::default()),
+            *b"dispute "
+        ));
+        assert_ok!(<SUT as LockableUniqueAssets<_>>::lock(
+            &default_commodity_id(Vec::<u8>::default()),
+            *b"loan    "
+        ));
+
+        <SUT as LockableUniqueAssets<_>>::unlock(
+            &default_commodity_id(Vec::<u8>::default()),
+            *b"dispute ",
+        );
+        assert_eq!(
+            SUT::locks::<H256>(default_commodity_id(Vec::<u8>::default())),
+            vec![*b"loan    "]
+        );
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(1),
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::CommodityLocked
+        );
+
+        <SUT as LockableUniqueAssets<_>>::unlock(
+            &default_commodity_id(Vec::<u8>::default()),
+            *b"loan    ",
+        );
+        assert!(!<SUT as LockableUniqueAssets<_>>::is_locked(
+            &default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+    });
+}
+
+#[test]
+fn buy_locked_commodity() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::list_for_sale(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default()),
+            50
+        ));
+        assert_ok!(<SUT as LockableUniqueAssets<_>>::lock(
+            &default_commodity_id(Vec::<u8>::default()),
+            *b"dispute "
+        ));
+
+        assert_err!(
+            SUT::buy(
+                Origin::signed(2),
+                default_commodity_id(Vec::<u8>::default()),
+                50
+            ),
+            Error::<Test, DefaultInstance>::CommodityLocked
+        );
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(2), 100);
+    });
+}
+
+#[test]
+fn transfer_err_non_transferable() {
+    new_test_ext().execute_with(|| {
+        set_transferable(false);
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(1),
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::NonTransferable
+        );
+        assert_err!(
+            <SUT as UniqueAssets<_>>::transfer(&2, &default_commodity_id(Vec::<u8>::default())),
+            Error::<Test, DefaultInstance>::NonTransferable
+        );
+        assert_ok!(SUT::burn(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+    });
+}
+
+#[test]
+fn recover() {
+    new_test_ext().execute_with(|| {
+        set_transferable(false);
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::recover(
+                Origin::signed(1),
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(SUT::recover(
+            Origin::root(),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(SUT::total_for_account(2), 1);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            2
+        );
+    });
+}
+
+#[test]
+fn recover_err_locked() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(<SUT as LockableUniqueAssets<_>>::lock(
+            &default_commodity_id(Vec::<u8>::default()),
+            *b"dispute "
+        ));
+
+        assert_err!(
+            SUT::recover(
+                Origin::root(),
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::CommodityLocked
+        );
+    });
+}
+
+#[test]
+fn force_transfer() {
+    new_test_ext().execute_with(|| {
+        set_transferable(false);
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::force_transfer(
+                Origin::signed(1),
+                1,
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_err!(
+            SUT::force_transfer(
+                Origin::root(),
+                3,
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::UnexpectedOwner
+        );
+        assert_ok!(SUT::force_transfer(
+            Origin::root(),
+            1,
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(SUT::total_for_account(2), 1);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(Vec::<u8>::default())),
+            2
+        );
+    });
+}
+
+#[test]
+fn force_burn() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::force_burn(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(SUT::force_burn(
+            Origin::root(),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+        assert_eq!(SUT::total(), 0);
+        assert_eq!(SUT::total_for_account(1), 0);
+        assert_eq!(
+            SUT::commodity_info::<H256>(default_commodity_id(Vec::<u8>::default())),
+            None
+        );
+        assert_err!(
+            SUT::force_burn(Origin::root(), default_commodity_id(Vec::<u8>::default())),
+            Error::<Test, DefaultInstance>::NonexistentCommodity
+        );
+    });
+}
+
+#[test]
+fn pause() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_err!(
+            SUT::pause(Origin::signed(1), None),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(SUT::pause(Origin::root(), None));
+
+        assert_err!(
+            SUT::mint(Origin::root(), 1, vec![1u8]),
+            Error::<Test, DefaultInstance>::Paused
+        );
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(1),
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::Paused
+        );
+        assert_err!(
+            SUT::burn(
+                Origin::signed(1),
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::Paused
+        );
+        assert_err!(
+            <SUT as UniqueAssets<_>>::mint(&1, vec![1u8]),
+            Error::<Test, DefaultInstance>::Paused
+        );
+        assert_err!(
+            <SUT as UniqueAssets<_>>::transfer(&2, &default_commodity_id(Vec::<u8>::default())),
+            Error::<Test, DefaultInstance>::Paused
+        );
+        assert_err!(
+            <SUT as UniqueAssets<_>>::burn(&default_commodity_id(Vec::<u8>::default())),
+            Error::<Test, DefaultInstance>::Paused
+        );
+
+        assert_ok!(SUT::unpause(Origin::root(), None));
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            2,
+            default_commodity_id(Vec::<u8>::default())
+        ));
+    });
+}
+
+#[test]
+fn pause_operation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::pause(Origin::root(), Some(Operation::Transfer)));
+        assert_ok!(SUT::pause(Origin::root(), None));
+        assert_ok!(SUT::unpause(Origin::root(), None));
+
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![1u8]));
+        assert_err!(
+            SUT::transfer(
+                Origin::signed(1),
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::Paused
+        );
+        assert_err!(
+            SUT::force_transfer(
+                Origin::root(),
+                1,
+                2,
+                default_commodity_id(Vec::<u8>::default())
+            ),
+            Error::<Test, DefaultInstance>::Paused
+        );
+        assert_ok!(SUT::burn(
+            Origin::signed(1),
+            default_commodity_id(Vec::<u8>::default())
+        ));
+
+        assert_ok!(SUT::unpause(Origin::root(), Some(Operation::Transfer)));
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            2,
+            default_commodity_id(vec![1u8])
+        ));
+    });
+}
+
+#[test]
+fn mint_reserves_deposit() {
+    new_test_ext().execute_with(|| {
+        set_deposits(10, 1);
+        assert_eq!(SUT::deposit_for(&vec![1u8, 2, 3]), 14);
+
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![1u8, 2, 3]));
+        assert_eq!(
+            SUT::deposit::<H256>(default_commodity_id(vec![1u8, 2, 3])),
+            Some((1, 14))
+        );
+        assert_eq!(Balances::free_balance(1), 86);
+        assert_eq!(Balances::reserved_balance(1), 14);
+    });
+}
+
+#[test]
+fn mint_err_insufficient_deposit() {
+    new_test_ext().execute_with(|| {
+        set_deposits(200, 0);
+
+        assert_err!(
+            SUT::mint(Origin::root(), 1, Vec::<u8>::default()),
+            pallet_balances::Error::<Test, _>::InsufficientBalance
+        );
+        assert_eq!(SUT::total(), 0);
+        assert_eq!(SUT::total_for_account(1), 0);
+    });
+}
+
+#[test]
+fn transfer_moves_deposit() {
+    new_test_ext().execute_with(|| {
+        set_deposits(10, 1);
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![1u8, 2, 3]));
+
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            2,
+            default_commodity_id(vec![1u8, 2, 3])
+        ));
+        assert_eq!(Balances::free_balance(1), 86);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(2), 100);
+        assert_eq!(Balances::reserved_balance(2), 14);
+        assert_eq!(
+            SUT::deposit(default_commodity_id(vec![1u8, 2, 3])),
+            Some((2, 14))
+        );
+        assert_err!(
+            SUT::transfer(Origin::signed(2), 4, default_commodity_id(vec![1u8, 2, 3])),
+            pallet_balances::Error::<Test, _>::DeadAccount
+        );
+    });
+}
+
+#[test]
+fn transfer_moves_deposit_from_minter() {
+    new_test_ext().execute_with(|| {
+        set_deposits(10, 1);
+        assert_ok!(SUT::grant_mint_allowance(Origin::root(), 1, 1));
+        assert_ok!(SUT::mint(Origin::signed(1), 2, vec![1u8, 2, 3]));
+        assert_eq!(Balances::reserved_balance(1), 14);
+
+        assert_ok!(SUT::transfer(
+            Origin::signed(2),
+            3,
+            default_commodity_id(vec![1u8, 2, 3])
+        ));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::reserved_balance(3), 14);
+
+        assert_ok!(SUT::burn(
+            Origin::signed(3),
+            default_commodity_id(vec![1u8, 2, 3])
+        ));
+        assert_eq!(Balances::free_balance(1), 86);
+        assert_eq!(Balances::free_balance(3), 114);
+        assert_eq!(Balances::reserved_balance(3), 0);
+    });
+}
+
+#[test]
+fn burn_unreserves_deposit() {
+    new_test_ext().execute_with(|| {
+        set_deposits(10, 1);
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![1u8, 2, 3]));
+
+        assert_ok!(SUT::burn(
+            Origin::signed(1),
+            default_commodity_id(vec![1u8, 2, 3])
+        ));
+        assert_eq!(
+            SUT::deposit::<H256>(default_commodity_id(vec![1u8, 2, 3])),
+            None
+        );
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
+#[test]
+fn create_collection() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::create_collection(Origin::signed(1), 3, 1, vec![0u8; 17]),
+            Error::<Test, DefaultInstance>::MetadataTooLong
+        );
+        assert_err!(
+            SUT::create_collection(Origin::signed(4), 3, 1, Vec::new()),
+            pallet_balances::Error::<Test, _>::InsufficientBalance
+        );
+        assert_eq!(SUT::collection_count(), 0);
+
+        assert_ok!(SUT::create_collection(
+            Origin::signed(1),
+            3,
+            1,
+            b"kitties".to_vec()
+        ));
+        assert_eq!(SUT::collection_count(), 1);
+        assert_eq!(
+            SUT::collection(1),
+            Some(Collection {
+                owner: 1,
+                deposit: 10,
+                max_commodities: 3,
+                max_commodities_per_account: 1,
+                metadata: b"kitties".to_vec(),
+            })
+        );
+        assert_eq!(Balances::reserved_balance(1), 10);
+
+        // The owner of a collection may mint commodities in it.
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 2, vec![1u8]));
+        assert_eq!(SUT::total_in_collection(1), 1);
+    });
+}
+
+#[test]
+fn destroy_collection() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::create_collection(Origin::signed(1), 3, 1, Vec::new()));
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 2, vec![1u8]));
+
+        assert_err!(
+            SUT::destroy_collection(Origin::signed(2), 1),
+            Error::<Test, DefaultInstance>::NotCollectionOwner
+        );
+        assert_err!(
+            SUT::destroy_collection(Origin::signed(1), 2),
+            Error::<Test, DefaultInstance>::NonexistentCollection
+        );
+        assert_err!(
+            SUT::destroy_collection(Origin::signed(1), 0),
+            Error::<Test, DefaultInstance>::NonexistentCollection
+        );
+        assert_err!(
+            SUT::destroy_collection(Origin::signed(1), 1),
+            Error::<Test, DefaultInstance>::CollectionNotEmpty
+        );
+
+        assert_ok!(SUT::burn(
+            Origin::signed(2),
+            collection_commodity_id(1, vec![1u8])
+        ));
+        assert_ok!(SUT::destroy_collection(Origin::signed(1), 1));
+        assert_eq!(SUT::collection(1), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_err!(
+            SUT::mint_into(Origin::signed(1), 1, 2, vec![1u8]),
+            Error::<Test, DefaultInstance>::NonexistentCollection
+        );
+
+        // Collection IDs are not reused.
+        assert_ok!(SUT::create_collection(Origin::signed(1), 3, 1, Vec::new()));
+        assert_eq!(SUT::collection_count(), 2);
+    });
+}
+
+#[test]
+fn force_create_collection() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::force_create_collection(Origin::signed(1), 1, 3, 1, b"kitties".to_vec()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_err!(
+            SUT::force_create_collection(Origin::root(), 1, 3, 1, vec![0u8; 17]),
+            Error::<Test, DefaultInstance>::MetadataTooLong
+        );
+
+        assert_ok!(SUT::force_create_collection(
+            Origin::root(),
+            1,
+            3,
+            1,
+            b"kitties".to_vec()
+        ));
+        assert_ok!(SUT::force_create_collection(
+            Origin::root(),
+            2,
+            1,
+            1,
+            Vec::new()
+        ));
+
+        assert_eq!(SUT::collection_count(), 2);
+        assert_eq!(
+            SUT::collection(1),
+            Some(Collection {
+                owner: 1,
+                deposit: 0,
+                max_commodities: 3,
+                max_commodities_per_account: 1,
+                metadata: b"kitties".to_vec(),
+            })
+        );
+        assert_eq!(
+            SUT::collection(2).map(|collection| collection.owner),
+            Some(2)
+        );
+        assert_eq!(
+            SUT::team(2),
+            Some(Team {
+                issuer: 2,
+                admin: 2,
+                freezer: 2,
+            })
+        );
+        assert_eq!(SUT::collection(0), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
+#[test]
+fn mint_into() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::force_create_collection(
+            Origin::root(),
+            1,
+            3,
+            1,
+            Vec::new()
+        ));
+
+        assert_err!(
+            SUT::mint_into(Origin::signed(1), 2, 2, vec![1u8]),
+            Error::<Test, DefaultInstance>::NonexistentCollection
+        );
+        assert_err!(
+            SUT::mint_into(Origin::signed(2), 1, 2, vec![1u8]),
+            Error::<Test, DefaultInstance>::NoPermission
+        );
+        assert_err!(
+            SUT::mint_into(Origin::root(), 1, 2, vec![1u8]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 2, vec![1u8]));
+        let commodity_id: H256 = collection_commodity_id(1, vec![1u8]);
+        assert_eq!(SUT::account_for_commodity(commodity_id), 2);
+        assert_eq!(
+            <SUT as UniqueCollections<_>>::collection_of(&commodity_id),
+            1
+        );
+        assert_eq!(<SUT as UniqueCollections<_>>::total_in_collection(&1), 1);
+        assert_eq!(
+            <SUT as UniqueCollections<_>>::total_in_collection_for_account(&1, &2),
+            1
+        );
+        assert_eq!(SUT::total_in_collection(0), 0);
+        assert_eq!(SUT::total(), 1);
+        assert_eq!(SUT::total_for_account(2), 1);
+
+        // Commodities that are minted by way of `UniqueAssets` belong to the default collection.
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![2u8]));
+        assert_eq!(
+            <SUT as UniqueCollections<_>>::collection_of(&default_commodity_id(vec![2u8])),
+            0
+        );
+        assert_eq!(SUT::total_in_collection(0), 1);
+        assert_eq!(SUT::total_in_collection_for_account(0, 2), 1);
+    });
+}
+
+#[test]
+fn mint_into_same_info_in_each_collection() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::create_collection(Origin::signed(1), 3, 2, Vec::new()));
+        assert_ok!(SUT::create_collection(Origin::signed(2), 3, 2, Vec::new()));
+
+        // Each creator may mint its own "ticket #1".
+        assert_ok!(SUT::mint(Origin::root(), 3, b"ticket #1".to_vec()));
+        assert_ok!(SUT::mint_into(
+            Origin::signed(1),
+            1,
+            3,
+            b"ticket #1".to_vec()
+        ));
+        assert_ok!(SUT::mint_into(
+            Origin::signed(2),
+            2,
+            3,
+            b"ticket #1".to_vec()
+        ));
+        assert_eq!(SUT::total(), 3);
+        assert_eq!(
+            SUT::collection_for_commodity(collection_commodity_id(2, b"ticket #1".to_vec())),
+            2
+        );
+
+        assert_err!(
+            SUT::mint_into(Origin::signed(1), 1, 2, b"ticket #1".to_vec()),
+            Error::<Test, DefaultInstance>::CommodityExists
+        );
+    });
+}
+
+#[test]
+fn mint_into_ids_distinct_from_default_collection() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..16 {
+            assert_ok!(SUT::force_create_collection(
+                Origin::root(),
+                1,
+                3,
+                1,
+                Vec::new()
+            ));
+        }
+
+        // The encoding of collection 16 and empty info is the encoding of four zero bytes.
+        assert_eq!((16u32, Vec::<u8>::new()).encode(), vec![0u8; 4].encode());
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![0u8; 4]));
+        assert_ok!(SUT::mint_into(Origin::signed(1), 16, 3, Vec::new()));
+        assert_ne!(
+            default_commodity_id(vec![0u8; 4]),
+            collection_commodity_id(16, Vec::new())
+        );
+        assert_eq!(SUT::total(), 2);
+    });
+}
+
+#[test]
+fn mint_into_reserves_deposit_from_issuer() {
+    new_test_ext().execute_with(|| {
+        set_deposits(50, 0);
+        assert_ok!(SUT::create_collection(Origin::signed(1), 3, 2, Vec::new()));
+
+        // The recipient does not pay for a commodity that it did not mint, even if the commodity
+        // is frozen so that it may not be burned.
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 3, vec![1u8]));
+        let commodity_id: H256 = collection_commodity_id(1, vec![1u8]);
+        assert_ok!(SUT::freeze(Origin::signed(1), commodity_id));
+        assert_err!(
+            SUT::burn(Origin::signed(3), commodity_id),
+            Error::<Test, DefaultInstance>::CommodityLocked
+        );
+        assert_eq!(SUT::deposit(commodity_id), Some((1, 50)));
+        assert_eq!(Balances::reserved_balance(1), 60);
+        assert_eq!(Balances::reserved_balance(3), 0);
+
+        assert_err!(
+            SUT::mint_into(Origin::signed(1), 1, 3, vec![2u8]),
+            pallet_balances::Error::<Test, _>::InsufficientBalance
+        );
+
+        assert_ok!(SUT::thaw(Origin::signed(1), commodity_id));
+        assert_ok!(SUT::burn(Origin::signed(3), commodity_id));
+        assert_eq!(Balances::reserved_balance(1), 10);
+    });
+}
+
+#[test]
+fn mint_into_collection_limits() {
+    new_test_ext().execute_with(|| {
+        // The limits of a collection replace the configured limits.
+        assert_ok!(SUT::force_create_collection(
+            Origin::root(),
+            1,
+            6,
+            3,
+            Vec::new()
+        ));
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![1u8]));
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![2u8]));
+        assert_err!(
+            SUT::mint(Origin::root(), 2, vec![3u8]),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 2, vec![3u8]));
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 2, vec![4u8]));
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 2, vec![5u8]));
+        assert_err!(
+            SUT::mint_into(Origin::signed(1), 1, 2, vec![6u8]),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 3, vec![6u8]));
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 3, vec![7u8]));
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 1, vec![8u8]));
+        assert_err!(
+            SUT::mint_into(Origin::signed(1), 1, 1, vec![9u8]),
+            Error::<Test, DefaultInstance>::TooManyCommodities
+        );
+        assert_eq!(SUT::total(), 8);
+        assert_eq!(SUT::total_in_collection(1), 6);
+
+        // Burning a commodity makes room in its collection.
+        assert_ok!(SUT::burn(
+            Origin::signed(1),
+            collection_commodity_id(1, vec![8u8])
+        ));
+        assert_eq!(SUT::total_in_collection(1), 5);
+        assert_eq!(SUT::total_in_collection_for_account(1, 1), 0);
+        assert_eq!(
+            SUT::collection_for_commodity::<H256>(collection_commodity_id(1, vec![8u8])),
+            0
+        );
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 1, vec![9u8]));
+    });
+}
+
+#[test]
+fn transfer_collection_limits() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::force_create_collection(
+            Origin::root(),
+            1,
+            6,
+            1,
+            Vec::new()
+        ));
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 1, vec![1u8]));
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 2, vec![2u8]));
+        assert_ok!(SUT::mint(Origin::root(), 3, vec![3u8]));
+        assert_ok!(SUT::mint(Origin::root(), 3, vec![4u8]));
+
+        // Account 2 owns the maximum number of commodities in the collection, but none in the
+        // default collection.
+        assert_err!(
+            SUT::transfer(Origin::signed(1), 2, collection_commodity_id(1, vec![1u8])),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+        assert_ok!(SUT::transfer(
+            Origin::signed(3),
+            2,
+            default_commodity_id(vec![3u8])
+        ));
+
+        // Account 3 owns the maximum number of commodities in the default collection, but none
+        // in the collection.
+        assert_ok!(SUT::transfer(
+            Origin::signed(1),
+            3,
+            collection_commodity_id(1, vec![1u8])
+        ));
+        assert_eq!(SUT::total_in_collection_for_account(1, 1), 0);
+        assert_eq!(SUT::total_in_collection_for_account(1, 3), 1);
+        assert_eq!(SUT::total_in_collection_for_account(0, 2), 1);
+        assert_eq!(SUT::total_in_collection_for_account(0, 3), 1);
+        assert_eq!(SUT::total_for_account(3), 2);
+    });
+}
+
+#[test]
+fn set_team() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::create_collection(Origin::signed(1), 3, 1, Vec::new()));
+
+        assert_err!(
+            SUT::set_team(Origin::signed(2), 1, 2, 2, 2),
+            Error::<Test, DefaultInstance>::NotCollectionOwner
+        );
+        assert_err!(
+            SUT::set_team(Origin::root(), 1, 2, 2, 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_err!(
+            SUT::set_team(Origin::signed(1), 2, 2, 2, 2),
+            Error::<Test, DefaultInstance>::NonexistentCollection
+        );
+
+        assert_ok!(SUT::set_team(Origin::signed(1), 1, 2, 3, 3));
+        assert_eq!(
+            SUT::team(1),
+            Some(Team {
+                issuer: 2,
+                admin: 3,
+                freezer: 3,
+            })
+        );
+
+        // Only the issuer may mint commodities in the collection, even if it is not the owner.
+        assert_err!(
+            SUT::mint_into(Origin::signed(1), 1, 1, vec![1u8]),
+            Error::<Test, DefaultInstance>::NoPermission
+        );
+        assert_ok!(SUT::mint_into(Origin::signed(2), 1, 1, vec![1u8]));
+
+        // The team of the default collection is appointed by the commodity admin.
+        assert_err!(
+            SUT::set_team(Origin::signed(1), 0, 1, 1, 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(SUT::set_team(Origin::root(), 0, 1, 2, 3));
+        assert_eq!(SUT::team(0).map(|team| team.issuer), Some(1));
+    });
+}
+
+#[test]
+fn issuer_mints_default_collection() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::mint(Origin::signed(1), 2, vec![1u8]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(SUT::set_team(Origin::root(), 0, 1, 2, 3));
+        assert_err!(
+            SUT::mint(Origin::signed(2), 2, vec![1u8]),
+            Error::<Test, DefaultInstance>::NoPermission
+        );
+        assert_ok!(SUT::mint(Origin::signed(1), 2, vec![1u8]));
+        assert_ok!(SUT::mint_batch(Origin::signed(1), vec![(3, vec![2u8])]));
+        assert_ok!(SUT::mint_with_royalty(
+            Origin::signed(1),
+            3,
+            vec![3u8],
+            1,
+            Perbill::from_percent(10)
+        ));
+
+        // The commodity admin may still mint commodities in the default collection.
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![4u8]));
+        assert_eq!(SUT::total_in_collection(0), 4);
+    });
+}
+
+#[test]
+fn admin_forces_commodities() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::create_collection(Origin::signed(1), 3, 2, Vec::new()));
+        assert_ok!(SUT::set_team(Origin::signed(1), 1, 1, 2, 1));
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 3, vec![1u8]));
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 3, vec![2u8]));
+        assert_ok!(SUT::mint(Origin::root(), 3, vec![3u8]));
+
+        assert_err!(
+            SUT::force_transfer(
+                Origin::signed(1),
+                3,
+                1,
+                collection_commodity_id(1, vec![1u8])
+            ),
+            Error::<Test, DefaultInstance>::NoPermission
+        );
+        assert_ok!(SUT::force_transfer(
+            Origin::signed(2),
+            3,
+            1,
+            collection_commodity_id(1, vec![1u8])
+        ));
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(collection_commodity_id(1, vec![1u8])),
+            1
+        );
+
+        assert_err!(
+            SUT::force_burn(Origin::signed(1), collection_commodity_id(1, vec![2u8])),
+            Error::<Test, DefaultInstance>::NoPermission
+        );
+        assert_ok!(SUT::force_burn(
+            Origin::signed(2),
+            collection_commodity_id(1, vec![2u8])
+        ));
+        assert_eq!(SUT::total_in_collection(1), 1);
+
+        // The admin of a collection may not force the commodities of another collection.
+        assert_err!(
+            SUT::force_burn(Origin::signed(2), default_commodity_id(vec![3u8])),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // The force origin may force the commodities of every collection.
+        assert_ok!(SUT::force_burn(
+            Origin::root(),
+            collection_commodity_id(1, vec![1u8])
+        ));
+    });
+}
+
+#[test]
+fn freeze_thaw() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::create_collection(Origin::signed(1), 3, 2, Vec::new()));
+        assert_ok!(SUT::set_team(Origin::signed(1), 1, 1, 1, 2));
+        assert_ok!(SUT::mint_into(Origin::signed(1), 1, 3, vec![1u8]));
+        let commodity_id: H256 = collection_commodity_id(1, vec![1u8]);
+
+        assert_err!(
+            SUT::freeze(Origin::signed(1), commodity_id),
+            Error::<Test, DefaultInstance>::NoPermission
+        );
+        assert_ok!(SUT::freeze(Origin::signed(2), commodity_id));
+        assert_eq!(SUT::locks(commodity_id), vec![FREEZE_LOCK]);
+        assert_err!(
+            SUT::transfer(Origin::signed(3), 1, commodity_id),
+            Error::<Test, DefaultInstance>::CommodityLocked
+        );
+
+        // Thawing a commodity does not release the locks of other modules.
+        assert_ok!(<SUT as LockableUniqueAssets<_>>::lock(
+            &commodity_id,
+            *b"other   "
+        ));
+        assert_err!(
+            SUT::thaw(Origin::signed(1), commodity_id),
+            Error::<Test, DefaultInstance>::NoPermission
+        );
+        assert_ok!(SUT::thaw(Origin::signed(2), commodity_id));
+        assert_eq!(SUT::locks(commodity_id), vec![*b"other   "]);
+        <SUT as LockableUniqueAssets<_>>::unlock(&commodity_id, *b"other   ");
+        assert_ok!(SUT::transfer(Origin::signed(3), 1, commodity_id));
+
+        // The force origin may freeze and thaw the commodities of every collection.
+        assert_ok!(SUT::freeze(Origin::root(), commodity_id));
+        assert_ok!(SUT::thaw(Origin::root(), commodity_id));
+        assert_err!(
+            SUT::freeze(Origin::root(), H256::zero()),
+            Error::<Test, DefaultInstance>::NonexistentCommodity
+        );
+        assert_err!(
+            SUT::thaw(Origin::root(), H256::zero()),
+            Error::<Test, DefaultInstance>::NonexistentCommodity
+        );
+    });
+}
+
+#[test]
+fn transfer_ownership() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::create_collection(Origin::signed(1), 3, 1, Vec::new()));
+
+        assert_err!(
+            SUT::transfer_ownership(Origin::signed(2), 1, 2),
+            Error::<Test, DefaultInstance>::NotCollectionOwner
+        );
+        assert_err!(
+            SUT::transfer_ownership(Origin::signed(1), 0, 2),
+            Error::<Test, DefaultInstance>::NonexistentCollection
+        );
+        assert_err!(
+            SUT::transfer_ownership(Origin::signed(1), 1, 4),
+            pallet_balances::Error::<Test, _>::DeadAccount
+        );
+
+        assert_ok!(SUT::transfer_ownership(Origin::signed(1), 1, 2));
+        assert_eq!(
+            SUT::collection(1).map(|collection| collection.owner),
+            Some(2)
+        );
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 10);
+
+        // The team is unchanged until the new owner appoints a new one.
+        assert_eq!(SUT::team(1).map(|team| team.issuer), Some(1));
+        assert_err!(
+            SUT::set_team(Origin::signed(1), 1, 1, 1, 1),
+            Error::<Test, DefaultInstance>::NotCollectionOwner
+        );
+        assert_ok!(SUT::set_team(Origin::signed(2), 1, 2, 2, 2));
+
+        // The new owner reclaims the deposit when the collection is destroyed.
+        assert_ok!(SUT::destroy_collection(Origin::signed(2), 1));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(SUT::team(1), None);
+    });
+}
+
+#[test]
+fn mint_allowance() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::grant_mint_allowance(Origin::signed(1), 1, 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_err!(
+            SUT::mint(Origin::signed(1), 2, vec![1u8]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(SUT::grant_mint_allowance(Origin::root(), 1, 2));
+        assert_eq!(SUT::mint_allowance(1), 2);
+
+        assert_ok!(SUT::mint(Origin::signed(1), 2, vec![1u8]));
+        assert_eq!(SUT::mint_allowance(1), 1);
+        assert_eq!(
+            SUT::account_for_commodity::<H256>(default_commodity_id(vec![1u8])),
+            2
+        );
+
+        // The allowance is not used if the commodity cannot be minted.
+        assert_err!(
+            SUT::mint(Origin::signed(1), 2, vec![1u8]),
+            Error::<Test, DefaultInstance>::CommodityExists
+        );
+        assert_eq!(SUT::mint_allowance(1), 1);
+
+        assert_ok!(SUT::mint(Origin::signed(1), 2, vec![2u8]));
+        assert_eq!(SUT::mint_allowance(1), 0);
+        assert!(!MintAllowance::<Test, DefaultInstance>::contains_key(1));
+        assert_err!(
+            SUT::mint(Origin::signed(1), 3, vec![3u8]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // An allowance only applies to `mint`.
+        assert_ok!(SUT::grant_mint_allowance(Origin::root(), 1, 5));
+        assert_err!(
+            SUT::mint_batch(Origin::signed(1), vec![(3, vec![3u8])]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_err!(
+            SUT::mint_into(Origin::signed(1), 0, 3, vec![3u8]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn revoke_mint_allowance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::grant_mint_allowance(Origin::root(), 1, 2));
+        assert_ok!(SUT::grant_mint_allowance(Origin::root(), 2, 2));
+
+        assert_err!(
+            SUT::revoke_mint_allowance(Origin::signed(1), 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(SUT::revoke_mint_allowance(Origin::root(), 1));
+        assert_eq!(SUT::mint_allowance(1), 0);
+        assert_eq!(SUT::mint_allowance(2), 2);
+        assert_err!(
+            SUT::mint(Origin::signed(1), 3, vec![1u8]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(SUT::mint(Origin::signed(2), 3, vec![1u8]));
+    });
+}
+
+#[test]
+fn issuer_mint_ignores_allowance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::set_team(Origin::root(), 0, 1, 1, 1));
+        assert_ok!(SUT::grant_mint_allowance(Origin::root(), 1, 1));
+
+        assert_ok!(SUT::mint(Origin::signed(1), 2, vec![1u8]));
+        assert_eq!(SUT::mint_allowance(1), 1);
+
+        // Accounts that do not hold the issuer role fail with the error of the role check.
+        assert_err!(
+            SUT::mint(Origin::signed(2), 2, vec![2u8]),
+            Error::<Test, DefaultInstance>::NoPermission
+        );
+    });
+}
+
+#[test]
+fn mint_allowance_reserves_deposit_from_minter() {
+    new_test_ext().execute_with(|| {
+        set_deposits(60, 0);
+        assert_ok!(SUT::grant_mint_allowance(Origin::root(), 1, 3));
+
+        assert_ok!(SUT::mint(Origin::signed(1), 2, vec![1u8]));
+        assert_eq!(
+            SUT::deposit::<H256>(default_commodity_id(vec![1u8])),
+            Some((1, 60))
+        );
+        assert_eq!(Balances::reserved_balance(1), 60);
+        assert_eq!(Balances::reserved_balance(2), 0);
+
+        // The allowance is not used if the minter is unable to pay the deposit.
+        assert_err!(
+            SUT::mint(Origin::signed(1), 2, vec![2u8]),
+            pallet_balances::Error::<Test, _>::InsufficientBalance
+        );
+        assert_eq!(SUT::mint_allowance(1), 2);
+
+        assert_ok!(SUT::burn(
+            Origin::signed(2),
+            default_commodity_id(vec![1u8])
+        ));
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
+// Sign a mint voucher for the default instance as the specified account.
+fn sign_voucher(signer: u64, voucher: &MintVoucherOf<Test, DefaultInstance>) -> TestSignature {
+    TestSignature(
+        signer,
+        (<DefaultInstance as Instance>::PREFIX, voucher).encode(),
+    )
+}
+
+#[test]
+fn mint_with_voucher() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::create_collection(Origin::signed(1), 5, 2, vec![]));
+        assert_ok!(SUT::set_team(Origin::signed(1), 1, 2, 1, 1));
+
+        let voucher = MintVoucher {
+            collection_id: 1,
+            recipient: 3,
+            commodity_info: vec![1u8],
+            expiry: 10,
+            nonce: 7,
+        };
+        let signature = sign_voucher(2, &voucher);
+
+        // Any account may redeem the voucher on behalf of its recipient.
+        assert_ok!(SUT::mint_with_voucher(
+            Origin::signed(1),
+            voucher.clone(),
+            signature.clone()
+        ));
+        let commodity_id: H256 = collection_commodity_id(1, vec![1u8]);
+        assert_eq!(SUT::account_for_commodity(commodity_id), 3);
+        assert_eq!(SUT::collection_for_commodity(commodity_id), 1);
+        assert_eq!(SUT::total_in_collection_for_account(1, 3), 1);
+        assert!(SUT::voucher_redeemed(1, 7));
+
+        assert_err!(
+            SUT::mint_with_voucher(Origin::none(), voucher, signature),
+            Error::<Test, DefaultInstance>::VoucherAlreadyRedeemed
+        );
+    });
+}
+
+#[test]
+fn mint_with_voucher_unsigned() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::set_team(Origin::root(), 0, 2, 2, 2));
+
+        let voucher = MintVoucher {
+            collection_id: 0,
+            recipient: 3,
+            commodity_info: vec![1u8],
+            expiry: 10,
+            nonce: 0,
+        };
+        let signature = sign_voucher(2, &voucher);
+
+        assert_err!(
+            SUT::mint_with_voucher(Origin::root(), voucher.clone(), signature.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(SUT::mint_with_voucher(Origin::none(), voucher, signature));
+        assert_eq!(SUT::total_in_collection(0), 1);
+        assert!(SUT::voucher_redeemed(0, 0));
+    });
+}
+
+#[test]
+fn mint_with_voucher_deposit() {
+    new_test_ext().execute_with(|| {
+        set_deposits(30, 0);
+        assert_ok!(SUT::set_team(Origin::root(), 0, 2, 2, 2));
+
+        let voucher = MintVoucher {
+            collection_id: 0,
+            recipient: 3,
+            commodity_info: vec![1u8],
+            expiry: 10,
+            nonce: 0,
+        };
+        let signature = sign_voucher(2, &voucher);
+
+        // The caller pays the deposit for a signed redemption...
+        assert_ok!(SUT::mint_with_voucher(
+            Origin::signed(1),
+            voucher.clone(),
+            signature
+        ));
+        assert_eq!(
+            SUT::deposit::<H256>(default_commodity_id(vec![1u8])),
+            Some((1, 30))
+        );
+        assert_eq!(Balances::reserved_balance(1), 30);
+
+        // ...and the issuer pays for an unsigned redemption.
+        let voucher = MintVoucher {
+            commodity_info: vec![2u8],
+            nonce: 1,
+            ..voucher
+        };
+        let signature = sign_voucher(2, &voucher);
+        assert_ok!(SUT::mint_with_voucher(Origin::none(), voucher, signature));
+        assert_eq!(Balances::reserved_balance(2), 30);
+        assert_eq!(Balances::reserved_balance(3), 0);
+    });
+}
+
+#[test]
+fn mint_with_voucher_err_expired() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::set_team(Origin::root(), 0, 2, 2, 2));
+
+        let voucher = MintVoucher {
+            collection_id: 0,
+            recipient: 3,
+            commodity_info: vec![1u8],
+            expiry: 5,
+            nonce: 0,
+        };
+        let signature = sign_voucher(2, &voucher);
+
+        System::set_block_number(5);
+        assert_err!(
+            SUT::mint_with_voucher(Origin::signed(1), voucher, signature),
+            Error::<Test, DefaultInstance>::VoucherExpired
+        );
+        assert_eq!(SUT::total(), 0);
+    });
+}
+
+#[test]
+fn mint_with_voucher_err_signature() {
+    new_test_ext().execute_with(|| {
+        let voucher = MintVoucher {
+            collection_id: 0,
+            recipient: 3,
+            commodity_info: vec![1u8],
+            expiry: 10,
+            nonce: 0,
+        };
+
+        // Vouchers for the default collection may not be redeemed until its team is appointed.
+        assert_err!(
+            SUT::mint_with_voucher(
+                Origin::signed(1),
+                voucher.clone(),
+                sign_voucher(2, &voucher)
+            ),
+            Error::<Test, DefaultInstance>::NoPermission
+        );
+
+        assert_ok!(SUT::set_team(Origin::root(), 0, 2, 1, 1));
+        assert_err!(
+            SUT::mint_with_voucher(
+                Origin::signed(1),
+                voucher.clone(),
+                sign_voucher(1, &voucher)
+            ),
+            Error::<Test, DefaultInstance>::InvalidVoucherSignature
+        );
+
+        // The signature covers every field of the voucher.
+        let altered = MintVoucher {
+            recipient: 1,
+            ..voucher.clone()
+        };
+        assert_err!(
+            SUT::mint_with_voucher(Origin::signed(1), altered, sign_voucher(2, &voucher)),
+            Error::<Test, DefaultInstance>::InvalidVoucherSignature
+        );
+
+        // A voucher that was signed for another pallet instance may not be redeemed.
+        let signature = TestSignature(2, (<Instance1 as Instance>::PREFIX, &voucher).encode());
+        assert_err!(
+            SUT::mint_with_voucher(Origin::signed(1), voucher.clone(), signature),
+            Error::<Test, DefaultInstance>::InvalidVoucherSignature
+        );
+
+        let voucher = MintVoucher {
+            collection_id: 1,
+            ..voucher
+        };
+        assert_err!(
+            SUT::mint_with_voucher(
+                Origin::signed(1),
+                voucher.clone(),
+                sign_voucher(2, &voucher)
+            ),
+            Error::<Test, DefaultInstance>::NonexistentCollection
+        );
+        assert!(!SUT::voucher_redeemed(0, 0));
+    });
+}
+
+#[test]
+fn mint_with_voucher_err_mint() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::set_team(Origin::root(), 0, 2, 2, 2));
+
+        let voucher = MintVoucher {
+            collection_id: 0,
+            recipient: REJECTING_ACCOUNT,
+            commodity_info: vec![1u8],
+            expiry: 10,
+            nonce: 0,
+        };
+        assert_err!(
+            SUT::mint_with_voucher(
+                Origin::signed(1),
+                voucher.clone(),
+                sign_voucher(2, &voucher)
+            ),
+            Error::<Test, DefaultInstance>::CommodityRejected
+        );
+
+        // The voucher is not redeemed if its commodity cannot be minted.
+        assert!(!SUT::voucher_redeemed(0, 0));
+    });
+}
+
+#[test]
+fn validate_voucher() {
+    use frame_support::unsigned::{TransactionSource, ValidateUnsigned};
+    use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidityError};
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::set_team(Origin::root(), 0, 2, 2, 2));
+        System::set_block_number(4);
+
+        let voucher = MintVoucher {
+            collection_id: 0,
+            recipient: 3,
+            commodity_info: vec![1u8],
+            expiry: 10,
+            nonce: 0,
+        };
+        let call = Call::<Test>::mint_with_voucher(voucher.clone(), sign_voucher(2, &voucher));
+        let valid = SUT::validate_unsigned(TransactionSource::External, &call).unwrap();
+        assert_eq!(valid.priority, UnsignedPriority::get());
+        assert_eq!(valid.longevity, 6);
+        assert_eq!(valid.provides.len(), 1);
+        assert!(valid.requires.is_empty());
+        assert!(valid.propagate);
+
+        let forged = Call::<Test>::mint_with_voucher(voucher.clone(), sign_voucher(3, &voucher));
+        assert_eq!(
+            SUT::validate_unsigned(TransactionSource::External, &forged),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::BadProof
+            ))
+        );
+
+        assert_ok!(SUT::mint_with_voucher(
+            Origin::none(),
+            voucher.clone(),
+            sign_voucher(2, &voucher)
+        ));
+        assert_eq!(
+            SUT::validate_unsigned(TransactionSource::External, &call),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Stale))
+        );
+
+        // Vouchers whose commodities may not be minted are invalid.
+        let voucher = MintVoucher {
+            recipient: REJECTING_ACCOUNT,
+            nonce: 1,
+            ..voucher
+        };
+        let rejected = Call::<Test>::mint_with_voucher(voucher.clone(), sign_voucher(2, &voucher));
+        assert_eq!(
+            SUT::validate_unsigned(TransactionSource::External, &rejected),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+        );
+        let voucher = MintVoucher {
+            recipient: 3,
+            commodity_info: vec![2u8],
+            ..voucher
+        };
+        let call = Call::<Test>::mint_with_voucher(voucher.clone(), sign_voucher(2, &voucher));
+        assert_ok!(SUT::pause(Origin::root(), Some(Operation::Mint)));
+        assert_eq!(
+            SUT::validate_unsigned(TransactionSource::External, &call),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+        );
+        assert_ok!(SUT::unpause(Origin::root(), Some(Operation::Mint)));
+
+        // The issuer must be able to pay the deposit.
+        set_deposits(200, 0);
+        assert_eq!(
+            SUT::validate_unsigned(TransactionSource::External, &call),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Payment
+            ))
+        );
+
+        let other = Call::<Test>::burn(default_commodity_id(vec![1u8]));
+        assert_eq!(
+            SUT::validate_unsigned(TransactionSource::External, &other),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+        );
+    });
+}
+
+#[test]
+fn sequential_ids() {
+    assert_eq!(
+        <Sequential<Test> as GenerateAssetId<Vec<u8>, _>>::generate_asset_id(0, &vec![]),
+        H256::zero()
+    );
+    assert_eq!(
+        <Sequential<Test> as GenerateAssetId<Vec<u8>, _>>::generate_asset_id(258, &vec![]),
+        H256::from_low_u64_be(258)
+    );
+    assert_eq!(
+        <Sequential<Test> as GenerateAssetId<Vec<u8>, _>>::generate_asset_id(u128::MAX, &vec![]),
+        H256::from_slice(&[[0; 16], [0xff; 16]].concat())
+    );
+}
+
+#[test]
+fn mint_sequential_duplicate_info() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SequentialSUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SequentialSUT::mint(Origin::root(), 2, Vec::<u8>::default()));
+
+        assert_eq!(SequentialSUT::total(), 2);
+        assert_eq!(
+            SequentialSUT::account_for_commodity::<H256>(H256::from_low_u64_be(0)),
+            1
+        );
+        assert_eq!(
+            SequentialSUT::account_for_commodity::<H256>(H256::from_low_u64_be(1)),
+            2
+        );
+        assert_eq!(
+            SequentialSUT::commodity_info::<H256>(H256::from_low_u64_be(1)),
+            Some(Vec::<u8>::default())
+        );
+    });
+}
+
+#[test]
+fn mint_sequential_after_burn() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SequentialSUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SequentialSUT::burn(
+            Origin::signed(1),
+            H256::from_low_u64_be(0)
+        ));
+        assert_ok!(SequentialSUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+
+        assert_eq!(SequentialSUT::total(), 1);
+        assert_eq!(
+            SequentialSUT::account_for_commodity::<H256>(H256::from_low_u64_be(0)),
+            0
+        );
+        assert_eq!(
+            SequentialSUT::account_for_commodity::<H256>(H256::from_low_u64_be(1)),
+            1
+        );
+        assert_eq!(SUT::total(), 0);
+    });
+}
+
+#[test]
+fn migrate_to_double_map() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = default_commodity_id(Vec::<u8>::default());
+        frame_support::storage::migration::put_storage_value(
+            b"Commodity",
+            b"CommoditiesForAccount",
+            &1u64.using_encoded(Blake2_128Concat::hash),
+            vec![(commodity_id, Vec::<u8>::default())],
+        );
+        assert_eq!(SUT::storage_version(), Releases::V1_0_0);
+
+        SUT::on_runtime_upgrade();
+
+        assert_eq!(SUT::storage_version(), Releases::V3_0_0);
+        assert_eq!(
+            <SUT as UniqueAssets<_>>::assets_for_account(&1),
+            vec![(commodity_id, Vec::<u8>::default())]
+        );
+        assert_eq!(
+            SUT::commodity_info(commodity_id),
+            Some(Vec::<u8>::default())
+        );
+    });
+}
+
+#[test]
+fn migrate_to_collections() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = default_commodity_id(Vec::<u8>::default());
+        Total::<DefaultInstance>::put(1);
+        TotalForAccount::<Test, DefaultInstance>::insert(1, 1);
+        CommoditiesForAccount::<Test, DefaultInstance>::insert(1, commodity_id, ());
+        Commodities::<Test, DefaultInstance>::insert(commodity_id, Vec::<u8>::default());
+        AccountForCommodity::<Test, DefaultInstance>::insert(commodity_id, 1);
+        StorageVersion::<DefaultInstance>::put(Releases::V2_0_0);
+
+        SUT::on_runtime_upgrade();
+
+        assert_eq!(SUT::storage_version(), Releases::V3_0_0);
+        assert_eq!(SUT::total_in_collection(0), 1);
+        assert_eq!(SUT::total_in_collection_for_account(0, 1), 1);
+        assert!(CommoditiesInCollection::<Test, DefaultInstance>::contains_key(0, commodity_id));
+        assert_eq!(
+            <SUT as UniqueCollections<_>>::collection_of(&commodity_id),
+            0
+        );
+
+        // The default collection is limited by the configured limits.
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![1u8]));
+        assert_err!(
+            SUT::mint(Origin::root(), 1, vec![2u8]),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
     });
 }
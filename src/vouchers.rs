@@ -0,0 +1,132 @@
+//! Vouchers for minting commodities.
+//!
+//! The issuer of a collection may sign vouchers off-chain, each of which entitles its recipient to
+//! a commodity in the collection, rather than minting every commodity up front (lazy minting). A
+//! voucher may be redeemed by way of a signed transaction from any account, which pays the storage
+//! deposit for the commodity, or an unsigned transaction, in which case the issuer that signed the
+//! voucher pays the deposit and no transaction fee is paid. An unsigned transaction is validated by
+//! the pallet before it enters the transaction pool and is only valid if the commodity may be
+//! minted and the issuer is able to pay the deposit, so a voucher that cannot be redeemed may not
+//! be used to fill blocks with transactions that pay no fee.
+//!
+//! Each voucher is identified within its collection by a nonce that is chosen by the issuer and may
+//! only be redeemed once, before its expiry block. The signature covers the voucher and the prefix
+//! of the pallet instance, so a voucher for one instance may not be redeemed in another.
+
+use super::*;
+use frame_support::unsigned::{TransactionSource, TransactionValidity, ValidateUnsigned};
+use sp_runtime::{
+    traits::Verify,
+    transaction_validity::{InvalidTransaction, ValidTransaction},
+};
+
+/// A voucher that entitles its recipient to a commodity in a collection.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct MintVoucher<CollectionId, AccountId, CommodityInfo, BlockNumber> {
+    /// The ID of the collection that the commodity will belong to.
+    pub collection_id: CollectionId,
+    /// The account that will own the commodity.
+    pub recipient: AccountId,
+    /// The information that defines the commodity.
+    pub commodity_info: CommodityInfo,
+    /// The block at which the voucher expires; it must be redeemed before this block.
+    pub expiry: BlockNumber,
+    /// A number that identifies the voucher within its collection.
+    pub nonce: u64,
+}
+
+/// A voucher for a commodity of this pallet.
+pub type MintVoucherOf<T, I> = MintVoucher<
+    <T as Trait<I>>::CollectionId,
+    <T as frame_system::Trait>::AccountId,
+    <T as Trait<I>>::CommodityInfo,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// Ensure that a voucher has not expired or been redeemed and that it has been signed by the
+    /// issuer of its collection. Returns the issuer.
+    pub(crate) fn check_voucher(
+        voucher: &MintVoucherOf<T, I>,
+        signature: &T::VoucherSignature,
+    ) -> Result<T::AccountId, Error<T, I>> {
+        ensure!(
+            frame_system::Module::<T>::block_number() < voucher.expiry,
+            Error::<T, I>::VoucherExpired
+        );
+        ensure!(
+            !Self::voucher_redeemed(voucher.collection_id, voucher.nonce),
+            Error::<T, I>::VoucherAlreadyRedeemed
+        );
+
+        let team = match Self::team(voucher.collection_id) {
+            Some(team) => team,
+            // Vouchers for the default collection may only be signed once its team has been
+            // appointed.
+            None if voucher.collection_id.is_zero() => return Err(Error::<T, I>::NoPermission),
+            None => return Err(Error::<T, I>::NonexistentCollection),
+        };
+        let message = (I::PREFIX, voucher).encode();
+        ensure!(
+            signature.verify(&message[..], &team.issuer),
+            Error::<T, I>::InvalidVoucherSignature
+        );
+        Ok(team.issuer)
+    }
+
+    /// Mint the commodity of a voucher that has been checked, reserving its deposit from the
+    /// depositor, and record that the voucher has been redeemed.
+    pub(crate) fn redeem_voucher(
+        voucher: MintVoucherOf<T, I>,
+        depositor: &T::AccountId,
+    ) -> dispatch::result::Result<CommodityId<T>, dispatch::DispatchError> {
+        let commodity_id = Self::do_mint(
+            &voucher.collection_id,
+            Some(depositor),
+            &voucher.recipient,
+            voucher.commodity_info,
+        )?;
+        RedeemedVouchers::<T, I>::insert(voucher.collection_id, voucher.nonce, true);
+
+        Ok(commodity_id)
+    }
+}
+
+impl<T: Trait<I>, I: Instance> ValidateUnsigned for Module<T, I> {
+    type Call = Call<T, I>;
+
+    fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+        let (voucher, signature) = match call {
+            Call::mint_with_voucher(voucher, signature) => (voucher, signature),
+            _ => return InvalidTransaction::Call.into(),
+        };
+
+        let issuer = Self::check_voucher(voucher, signature).map_err(|error| match error {
+            Error::<T, I>::VoucherExpired | Error::<T, I>::VoucherAlreadyRedeemed => {
+                InvalidTransaction::Stale
+            }
+            Error::<T, I>::InvalidVoucherSignature => InvalidTransaction::BadProof,
+            _ => InvalidTransaction::Call,
+        })?;
+
+        // A voucher whose commodity may not be minted would remain valid after every failed
+        // attempt to redeem it.
+        Self::ensure_can_mint(
+            &voucher.collection_id,
+            &voucher.recipient,
+            &voucher.commodity_info,
+        )
+        .map_err(|_| InvalidTransaction::Call)?;
+        if !T::Currency::can_reserve(&issuer, Self::deposit_for(&voucher.commodity_info)) {
+            return InvalidTransaction::Payment.into();
+        }
+
+        let now = frame_system::Module::<T>::block_number();
+        ValidTransaction::with_tag_prefix("CommodityVoucher")
+            .priority(T::UnsignedPriority::get())
+            .and_provides((I::PREFIX, voucher.collection_id, voucher.nonce))
+            .longevity((voucher.expiry - now).saturated_into())
+            .propagate(true)
+            .build()
+    }
+}